@@ -0,0 +1,108 @@
+use anyhow::{format_err, Result};
+use std::fmt::{Display, Formatter};
+use uom::fmt::DisplayStyle::Abbreviation;
+use uom::si::electric_potential::{kilovolt, volt};
+use uom::si::f64::{ElectricPotential, Length};
+use uom::si::length::{meter, millimeter};
+
+use crate::cubicle::Cubicle;
+
+/// A shock protection approach boundary.
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub enum ApproachDistance {
+    NotSpecified,
+    AvoidContact,
+    Distance(Length),
+}
+
+/// Shock protection approach boundaries for AC systems, from NFPA 70E Table 130.4(E)(a).
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub struct ApproachBoundaries {
+    /// Limited approach boundary, exposed movable conductor.
+    pub limited_movable: ApproachDistance,
+    /// Limited approach boundary, exposed fixed circuit part.
+    pub limited_fixed: ApproachDistance,
+    /// Restricted approach boundary, including inadvertent movement adder.
+    pub restricted: ApproachDistance,
+}
+
+/// Calculate the shock protection approach boundaries for nominal system voltage `v` (phase to phase).
+///
+/// Voltages between the ranges of Table 130.4(E)(a) use the next higher range.
+pub fn approach_boundaries(v: ElectricPotential) -> Result<ApproachBoundaries> {
+    use ApproachDistance::*;
+
+    let v_kv = v.get::<kilovolt>();
+    let m = |d: f64| Distance(Length::new::<meter>(d));
+
+    // (limited movable, limited fixed, restricted)
+    let (limited_movable, limited_fixed, restricted) = if v < ElectricPotential::new::<volt>(50.0) {
+        (NotSpecified, NotSpecified, NotSpecified)
+    } else if v <= ElectricPotential::new::<volt>(150.0) {
+        (m(3.0), m(1.0), AvoidContact)
+    } else if v <= ElectricPotential::new::<volt>(750.0) {
+        (m(3.0), m(1.0), m(0.3))
+    } else if v_kv <= 15.0 {
+        (m(3.0), m(1.5), m(0.7))
+    } else if v_kv <= 36.0 {
+        (m(3.0), m(1.8), m(0.8))
+    } else if v_kv <= 46.0 {
+        (m(3.0), m(2.5), m(0.8))
+    } else if v_kv <= 72.5 {
+        (m(3.0), m(2.5), m(1.0))
+    } else if v_kv <= 121.0 {
+        (m(3.3), m(2.5), m(1.0))
+    } else if v_kv <= 145.0 {
+        (m(3.4), m(3.0), m(1.2))
+    } else if v_kv <= 169.0 {
+        (m(3.6), m(3.6), m(1.3))
+    } else if v_kv <= 242.0 {
+        (m(4.0), m(4.0), m(1.7))
+    } else if v_kv <= 362.0 {
+        (m(4.7), m(4.7), m(2.8))
+    } else if v_kv <= 550.0 {
+        (m(5.8), m(5.8), m(3.6))
+    } else if v_kv <= 800.0 {
+        (m(7.2), m(7.2), m(4.9))
+    } else {
+        return Err(format_err!(
+            "no approach boundaries are given for voltages above 800 kV: {}",
+            v.into_format_args(kilovolt, Abbreviation)
+        ));
+    };
+
+    Ok(ApproachBoundaries {
+        limited_movable,
+        limited_fixed,
+        restricted,
+    })
+}
+
+impl Cubicle {
+    /// Shock protection approach boundaries for the nominal voltage `V_oc` of the cubicle.
+    pub fn approach_boundaries(&self) -> Result<ApproachBoundaries> {
+        approach_boundaries(self.v_oc)
+    }
+}
+
+impl Display for ApproachDistance {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ApproachDistance::NotSpecified => write!(f, "not specified"),
+            ApproachDistance::AvoidContact => write!(f, "avoid contact"),
+            ApproachDistance::Distance(d) => {
+                write!(f, "{}", d.into_format_args(millimeter, Abbreviation))
+            }
+        }
+    }
+}
+
+impl Display for ApproachBoundaries {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Limited approach (movable conductor) = {}, Limited approach (fixed circuit part) = {}, Restricted approach = {}",
+            self.limited_movable, self.limited_fixed, self.restricted
+        )
+    }
+}
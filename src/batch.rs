@@ -0,0 +1,281 @@
+use anyhow::{format_err, Result};
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use uom::si::electric_current::kiloampere;
+use uom::si::f64::{ElectricCurrent, ElectricPotential, Frequency, Length, Time};
+use uom::si::length::millimeter;
+use uom::si::radiant_exposure::joule_per_square_centimeter;
+use uom::si::time::millisecond;
+
+use crate::common::ElectrodeConfiguration;
+use crate::cubicle::{BoxDimensions, Cubicle};
+use crate::error::ArcFlashError;
+use crate::method::{evaluate_method, ArcFlashResult, Ieee2018, MethodEvaluation};
+use crate::network::MethodFor;
+use crate::quantity::{
+    parse_box, parse_current, parse_frequency, parse_length, parse_time, parse_voltage,
+};
+use crate::scenario::{ArcingCurrentCase, FixedClearingTimes};
+use crate::warning::Warning;
+
+/// One row of a batch study, i.e. one bus.
+pub struct BatchRow {
+    pub name: String,
+    pub v_oc: ElectricPotential,
+    pub ec: ElectrodeConfiguration,
+    pub g: Length,
+    pub d: Length,
+    pub dim: BoxDimensions,
+    /// System frequency, checked with the other cubicle parameters if given.
+    pub frequency: Option<Frequency>,
+    pub i_bf: ElectricCurrent,
+    /// Clearing time for the full arcing current.
+    pub t_full: Time,
+    /// Clearing time for the reduced arcing current.
+    pub t_reduced: Time,
+}
+
+/// Result of one row of a batch study.
+pub struct BatchResult {
+    pub name: String,
+    pub result: Result<(Cubicle, MethodEvaluation)>,
+}
+
+/// Appends the default unit to values given as a plain number.
+fn with_unit(value: &str, unit: &str) -> String {
+    if value.trim().parse::<f64>().is_ok() {
+        format!("{}{}", value.trim(), unit)
+    } else {
+        value.to_string()
+    }
+}
+
+impl BatchRow {
+    /// Reads a row from its fields, named and with values as for the columns of `read_batch` (the command line
+    /// options of `arcflash calc` are read in the same way).
+    pub fn from_fields(name: String, fields: &HashMap<String, String>) -> Result<Self> {
+        let get = |key: &str| {
+            fields
+                .get(key)
+                .map(String::as_str)
+                .filter(|value| !value.is_empty())
+        };
+        let required = |key: &str| get(key).ok_or_else(|| format_err!("missing {}", key));
+
+        let dim = match get("box") {
+            // Dimensions without a unit take the unit of the last dimension.
+            Some(dim) if dim.ends_with(|c: char| c.is_ascii_digit()) => {
+                parse_box(&format!("{}mm", dim))?
+            }
+            Some(dim) => parse_box(dim)?,
+            None => BoxDimensions {
+                height: parse_length(&with_unit(required("height")?, "mm"))?,
+                width: parse_length(&with_unit(required("width")?, "mm"))?,
+                depth: parse_length(&with_unit(required("depth")?, "mm"))?,
+            },
+        };
+        let t_full = parse_time(&with_unit(required("t")?, "ms"))?;
+
+        Ok(Self {
+            name,
+            v_oc: parse_voltage(&with_unit(required("voltage")?, "kV"))?,
+            ec: required("ec")?.parse()?,
+            g: parse_length(&with_unit(required("gap")?, "mm"))?,
+            d: parse_length(&with_unit(required("distance")?, "mm"))?,
+            dim,
+            frequency: match get("frequency") {
+                Some(f) => Some(parse_frequency(&with_unit(f, "Hz"))?),
+                None => None,
+            },
+            i_bf: parse_current(&with_unit(required("ibf")?, "kA"))?,
+            t_full,
+            t_reduced: match get("t_reduced").or(get("t-reduced")) {
+                Some(t) => parse_time(&with_unit(t, "ms"))?,
+                None => t_full,
+            },
+        })
+    }
+
+    /// Build the cubicle of the row, checking its parameters (and frequency, if given) against the range of the
+    /// model.
+    pub fn cubicle(&self) -> Result<Cubicle, ArcFlashError> {
+        let cubicle = Cubicle::new(
+            self.v_oc,
+            self.ec,
+            self.g,
+            self.d,
+            self.dim.height,
+            self.dim.width,
+            self.dim.depth,
+        )?;
+        match self.frequency {
+            Some(f) => cubicle.with_frequency(f),
+            None => Ok(cubicle),
+        }
+    }
+
+    /// Build the cubicle and calculate the full and reduced arcing current cases by the method that `method`
+    /// returns for it.
+    pub fn evaluate(&self, method: &MethodFor) -> Result<(Cubicle, MethodEvaluation)> {
+        let cubicle = self.cubicle()?;
+        let clearing = FixedClearingTimes {
+            full: self.t_full,
+            reduced: self.t_reduced,
+        };
+        let evaluation = evaluate_method(method(&cubicle).as_ref(), self.i_bf, &clearing, None)?;
+
+        Ok((cubicle, evaluation))
+    }
+}
+
+/// Reads the rows of a batch study from CSV.
+///
+/// The first row is a header. Columns are `name` (optional), `voltage`, `ec`, `gap`, `distance`, either `box`
+/// (e.g. "1143x762x508") or `height`, `width` and `depth`, `ibf`, `t`, `t_reduced` (optional, defaults to `t`;
+/// `t-reduced` is also accepted), and `frequency` (optional).
+///
+/// Values may include a unit (e.g. "4.16kV", "104mm", "15kA", "197ms", "60Hz"). Plain numbers are taken to be in
+/// kV, mm, kA, ms, and Hz.
+///
+/// A row that cannot be read is returned as an error, without affecting the other rows.
+pub fn read_batch<R: Read>(rdr: R) -> Result<Vec<(String, Result<BatchRow>)>> {
+    let mut rdr = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .comment(Some(b'#'))
+        .flexible(true)
+        .from_reader(rdr);
+
+    let headers: Vec<String> = rdr.headers()?.iter().map(|h| h.to_lowercase()).collect();
+
+    let mut rows = Vec::new();
+    for (i, record) in rdr.records().enumerate() {
+        let row = i + 1;
+        let name = format!("row {}", row);
+
+        let record = match record {
+            Ok(record) => record,
+            Err(err) => {
+                rows.push((name, Err(err.into())));
+                continue;
+            }
+        };
+        let fields: HashMap<String, String> = headers
+            .iter()
+            .cloned()
+            .zip(record.iter().map(str::to_string))
+            .collect();
+
+        let name = match fields.get("name") {
+            Some(n) if !n.is_empty() => n.clone(),
+            _ => name,
+        };
+        rows.push((name.clone(), BatchRow::from_fields(name, &fields)));
+    }
+
+    Ok(rows)
+}
+
+/// Calculates each row of a batch study by IEEE 1584-2018.
+pub fn run_batch(rows: Vec<(String, Result<BatchRow>)>) -> Vec<BatchResult> {
+    run_batch_with(rows, &|cubicle| Box::new(Ieee2018 { cubicle }))
+}
+
+/// Calculates each row of a batch study by the method that `method` returns for the cubicle of the row.
+pub fn run_batch_with(
+    rows: Vec<(String, Result<BatchRow>)>,
+    method: &MethodFor,
+) -> Vec<BatchResult> {
+    rows.into_iter()
+        .map(|(name, row)| BatchResult {
+            name,
+            result: row.and_then(|row| row.evaluate(method)),
+        })
+        .collect()
+}
+
+/// Columns of the results of a batch study, in the order written by `write_results`.
+const RESULT_COLUMNS: [&str; 16] = [
+    "name",
+    "i_arc_full_ka",
+    "i_arc_reduced_ka",
+    "t_full_ms",
+    "t_reduced_ms",
+    "e_full_j_per_cm2",
+    "e_reduced_j_per_cm2",
+    "afb_full_mm",
+    "afb_reduced_mm",
+    "governing",
+    "e_j_per_cm2",
+    "afb_mm",
+    "cf",
+    "var_cf",
+    "warnings",
+    "error",
+];
+const NAME_COLUMN: usize = 0;
+const ERROR_COLUMN: usize = RESULT_COLUMNS.len() - 1;
+
+/// Writes the results of a batch study as CSV.
+///
+/// Currents are in kA, times in ms, energies in J/cm² and distances in mm. `e` and `afb` are the values of the
+/// governing case, and `warnings` are those of the governing case, separated by "; ". Rows that failed have an
+/// `error` and no results.
+pub fn write_results<W: Write>(wtr: W, results: &[BatchResult]) -> Result<()> {
+    let mut wtr = csv::Writer::from_writer(wtr);
+
+    wtr.write_record(RESULT_COLUMNS)?;
+
+    for result in results {
+        match &result.result {
+            Ok((cubicle, evaluation)) => {
+                // A method without a reduced case leaves its columns empty.
+                let value = |case: ArcingCurrentCase, f: fn(&dyn ArcFlashResult) -> f64| {
+                    evaluation
+                        .case(case)
+                        .map(|c| f(c.result.as_ref()).to_string())
+                        .unwrap_or_default()
+                };
+                let i_arc = |r: &dyn ArcFlashResult| r.i_arc().get::<kiloampere>();
+                let t_arc = |r: &dyn ArcFlashResult| r.t_arc().get::<millisecond>();
+                let e = |r: &dyn ArcFlashResult| r.e().get::<joule_per_square_centimeter>();
+                let afb = |r: &dyn ArcFlashResult| r.afb().get::<millimeter>();
+                let (full, reduced) = (ArcingCurrentCase::Full, ArcingCurrentCase::Reduced);
+                let governing = evaluation.governing();
+                let record: [String; RESULT_COLUMNS.len()] = [
+                    result.name.clone(),
+                    value(full, i_arc),
+                    value(reduced, i_arc),
+                    value(full, t_arc),
+                    value(reduced, t_arc),
+                    value(full, e),
+                    value(reduced, e),
+                    value(full, afb),
+                    value(reduced, afb),
+                    format!("{:?}", governing.case),
+                    e(governing.result.as_ref()).to_string(),
+                    afb(governing.result.as_ref()).to_string(),
+                    cubicle.cf.to_string(),
+                    cubicle.var_cf.to_string(),
+                    governing
+                        .result
+                        .diagnostics()
+                        .iter()
+                        .map(Warning::to_string)
+                        .collect::<Vec<_>>()
+                        .join("; "),
+                    String::new(),
+                ];
+                wtr.write_record(&record)?;
+            }
+            Err(err) => {
+                let mut record: [String; RESULT_COLUMNS.len()] = Default::default();
+                record[NAME_COLUMN] = result.name.clone();
+                record[ERROR_COLUMN] = err.to_string();
+                wtr.write_record(&record)?;
+            }
+        }
+    }
+
+    wtr.flush()?;
+    Ok(())
+}
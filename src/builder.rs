@@ -0,0 +1,112 @@
+use uom::si::f64::{ElectricPotential, Frequency, Length};
+use uom::si::length::millimeter as mm;
+
+use crate::common::{ElectrodeConfiguration, EquipmentClass};
+use crate::cubicle::Cubicle;
+use crate::error::ArcFlashError;
+
+/// Builds a `Cubicle` from the typical parameters of an equipment class, with any of them overridden.
+///
+/// E.g. for 480 V switchgear with a 32 mm gap, 508 x 508 x 508 mm enclosure and 609.6 mm working distance:
+///
+/// ```
+/// # use arcflash::{CubicleBuilder, EquipmentClass};
+/// # use uom::si::{electric_potential::volt, f64::ElectricPotential};
+/// let cubicle = CubicleBuilder::new(EquipmentClass::LvSwitchgear, ElectricPotential::new::<volt>(480.0))
+///     .build()
+///     .unwrap();
+/// ```
+///
+/// The system frequency is not checked unless it is set.
+pub struct CubicleBuilder {
+    v_oc: ElectricPotential,
+    ec: ElectrodeConfiguration,
+    g: Length,
+    d: Length,
+    height: Length,
+    width: Length,
+    depth: Length,
+    frequency: Option<Frequency>,
+}
+
+impl CubicleBuilder {
+    /// A builder with the typical parameters of `class` (see `EquipmentClass::typical_params`) at voltage `v_oc`. The
+    /// electrode configuration defaults to VCB; set it with `ec`.
+    pub fn new(class: EquipmentClass, v_oc: ElectricPotential) -> Self {
+        let params = class.typical_params();
+
+        Self {
+            v_oc,
+            ec: ElectrodeConfiguration::VCB,
+            g: Length::new::<mm>(params.g),
+            d: Length::new::<mm>(params.d),
+            height: Length::new::<mm>(params.bh),
+            width: Length::new::<mm>(params.bw),
+            depth: Length::new::<mm>(params.bd),
+            frequency: None,
+        }
+    }
+
+    pub fn ec(mut self, ec: ElectrodeConfiguration) -> Self {
+        self.ec = ec;
+        self
+    }
+
+    /// Busbar gap.
+    pub fn gap(mut self, g: Length) -> Self {
+        self.g = g;
+        self
+    }
+
+    /// Working distance.
+    pub fn distance(mut self, d: Length) -> Self {
+        self.d = d;
+        self
+    }
+
+    /// Enclosure height, width, and depth.
+    pub fn dimensions(mut self, height: Length, width: Length, depth: Length) -> Self {
+        self.height = height;
+        self.width = width;
+        self.depth = depth;
+        self
+    }
+
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn depth(mut self, depth: Length) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    /// System frequency, checked by `build` (see `Cubicle::with_frequency`).
+    pub fn frequency(mut self, f: Frequency) -> Self {
+        self.frequency = Some(f);
+        self
+    }
+
+    /// Build the cubicle, as for `Cubicle::new`.
+    pub fn build(self) -> Result<Cubicle, ArcFlashError> {
+        let c = Cubicle::new(
+            self.v_oc,
+            self.ec,
+            self.g,
+            self.d,
+            self.height,
+            self.width,
+            self.depth,
+        )?;
+        match self.frequency {
+            Some(f) => c.with_frequency(f),
+            None => Ok(c),
+        }
+    }
+}
@@ -0,0 +1,258 @@
+#[cfg(feature = "serde")]
+use anyhow::Result;
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+#[cfg(feature = "serde")]
+use std::io::Read;
+use std::sync::Arc;
+
+use crate::common::{ElectrodeConfiguration, EnclosureType, NominalVoltage};
+use crate::error::ArcFlashError;
+use crate::tables::{TABLE_1, TABLE_2, TABLE_3, TABLE_4, TABLE_5, TABLE_7};
+
+pub use crate::tables::{Table1Row, Table2Row, Table3_4_5Row, Table7Row};
+
+const ELECTRODE_CONFIGURATIONS: [ElectrodeConfiguration; 5] = [
+    ElectrodeConfiguration::VCB,
+    ElectrodeConfiguration::VCBB,
+    ElectrodeConfiguration::HCB,
+    ElectrodeConfiguration::VOA,
+    ElectrodeConfiguration::HOA,
+];
+
+const NOMINAL_VOLTAGES: [NominalVoltage; 3] = [
+    NominalVoltage::V600,
+    NominalVoltage::V2700,
+    NominalVoltage::V14300,
+];
+
+/// For lookups in the set of a cubicle, which cannot fail: the set is checked before a cubicle can hold it.
+pub(crate) const CHECKED: &str = "coefficient set of a cubicle is checked";
+
+lazy_static! {
+    /// The published coefficients, shared by every cubicle that does not have its own.
+    pub(crate) static ref IEEE_1584_2018: Arc<CoefficientSet> =
+        Arc::new(CoefficientSet::ieee_1584_2018());
+}
+
+/// The coefficients of the IEEE 1584-2018 model (Tables 1 to 5 and 7), e.g. for evaluating coefficients fitted to
+/// new test data through the same equations as the published values.
+///
+/// The default is the published set. A cubicle holds the set it was created with (see
+/// `Cubicle::with_coefficients`, which checks the set), which is then used by `i_arc`, `e_afb` and everything built
+/// on them.
+///
+/// With the `serde` feature, a set can be read from and written to TOML or JSON, with the tables keyed by
+/// nominal voltage (`V600`, `V2700`, `V14300`), electrode configuration and enclosure type, e.g.:
+///
+/// ```toml
+/// name = "Calibrated"
+///
+/// [table_1.V600.VCB]
+/// k1 = -0.04287
+/// k2 = 1.035
+/// # ...
+/// ```
+#[derive(PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CoefficientSet {
+    /// Name of the set, for reports.
+    pub name: String,
+    /// Table 1: intermediate arcing current, by intermediate voltage.
+    pub table_1: HashMap<NominalVoltage, HashMap<ElectrodeConfiguration, Table1Row>>,
+    /// Table 2: arcing current variation correction factor.
+    pub table_2: HashMap<ElectrodeConfiguration, Table2Row>,
+    /// Table 3: intermediate incident energy at 600 V.
+    pub table_3: HashMap<ElectrodeConfiguration, Table3_4_5Row>,
+    /// Table 4: intermediate incident energy at 2700 V.
+    pub table_4: HashMap<ElectrodeConfiguration, Table3_4_5Row>,
+    /// Table 5: intermediate incident energy at 14 300 V.
+    pub table_5: HashMap<ElectrodeConfiguration, Table3_4_5Row>,
+    /// Table 7: enclosure size correction factor, by enclosure type.
+    pub table_7: HashMap<EnclosureType, HashMap<ElectrodeConfiguration, Table7Row>>,
+}
+
+impl CoefficientSet {
+    /// The coefficients published in IEEE 1584-2018.
+    pub fn ieee_1584_2018() -> Self {
+        let mut table_1: HashMap<_, HashMap<_, _>> = HashMap::new();
+        for ((ec, v_oc), row) in TABLE_1.iter() {
+            table_1.entry(*v_oc).or_default().insert(*ec, *row);
+        }
+        let mut table_7: HashMap<_, HashMap<_, _>> = HashMap::new();
+        for ((enclosure_type, ec), row) in TABLE_7.iter() {
+            table_7
+                .entry(*enclosure_type)
+                .or_default()
+                .insert(*ec, *row);
+        }
+        let copy = |table: &HashMap<ElectrodeConfiguration, Table3_4_5Row>| {
+            table.iter().map(|(ec, row)| (*ec, *row)).collect()
+        };
+
+        Self {
+            name: "IEEE 1584-2018".to_string(),
+            table_1,
+            table_2: TABLE_2.iter().map(|(ec, row)| (*ec, *row)).collect(),
+            table_3: copy(&TABLE_3),
+            table_4: copy(&TABLE_4),
+            table_5: copy(&TABLE_5),
+            table_7,
+        }
+    }
+
+    /// Checks that the set has a row for every electrode configuration, intermediate voltage and enclosure type
+    /// used by the model, and that every coefficient is finite.
+    pub fn check(&self) -> Result<(), ArcFlashError> {
+        // The number (from 1) of the first coefficient that is not finite.
+        fn not_finite(k: &[f64]) -> Option<usize> {
+            k.iter().position(|k| !k.is_finite()).map(|i| i + 1)
+        }
+
+        for v_oc in NOMINAL_VOLTAGES {
+            for ec in ELECTRODE_CONFIGURATIONS {
+                let k = self.table_1_row(ec, v_oc)?;
+                let k = [k.k1, k.k2, k.k3, k.k4, k.k5, k.k6, k.k7, k.k8, k.k9, k.k10];
+                if let Some(i) = not_finite(&k) {
+                    return Err(invalid(1, Some(v_oc), None, ec, Some(i)));
+                }
+            }
+        }
+
+        for ec in ELECTRODE_CONFIGURATIONS {
+            let k = self.table_2_row(ec)?;
+            if let Some(i) = not_finite(&[k.k1, k.k2, k.k3, k.k4, k.k5, k.k6, k.k7]) {
+                return Err(invalid(2, None, None, ec, Some(i)));
+            }
+        }
+
+        for (table, v_oc) in [3, 4, 5].into_iter().zip(NOMINAL_VOLTAGES) {
+            for ec in ELECTRODE_CONFIGURATIONS {
+                let k = self.table_3_4_5_row(ec, v_oc)?;
+                let k = [
+                    k.k1, k.k2, k.k3, k.k4, k.k5, k.k6, k.k7, k.k8, k.k9, k.k10, k.k11, k.k12,
+                    k.k13,
+                ];
+                if let Some(i) = not_finite(&k) {
+                    return Err(invalid(table, None, None, ec, Some(i)));
+                }
+            }
+        }
+
+        // Open air configurations have no enclosure size correction factor.
+        for enclosure_type in [EnclosureType::Typical, EnclosureType::Shallow] {
+            for ec in ELECTRODE_CONFIGURATIONS[..3].iter().copied() {
+                let b = self.table_7_row(enclosure_type, ec)?;
+                if let Some(i) = not_finite(&[b.b1, b.b2, b.b3]) {
+                    return Err(invalid(7, None, Some(enclosure_type), ec, Some(i)));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// True if the coefficients are those published in IEEE 1584-2018 (whatever the name of the set).
+    pub fn is_ieee_1584_2018(&self) -> bool {
+        let standard = &**IEEE_1584_2018;
+        self.table_1 == standard.table_1
+            && self.table_2 == standard.table_2
+            && self.table_3 == standard.table_3
+            && self.table_4 == standard.table_4
+            && self.table_5 == standard.table_5
+            && self.table_7 == standard.table_7
+    }
+
+    /// Table 1 row for electrode configuration `ec` at intermediate voltage `v_oc`.
+    pub fn table_1_row(
+        &self,
+        ec: ElectrodeConfiguration,
+        v_oc: NominalVoltage,
+    ) -> Result<&Table1Row, ArcFlashError> {
+        self.table_1
+            .get(&v_oc)
+            .and_then(|rows| rows.get(&ec))
+            .ok_or(invalid(1, Some(v_oc), None, ec, None))
+    }
+
+    /// Table 2 row for electrode configuration `ec`.
+    pub fn table_2_row(&self, ec: ElectrodeConfiguration) -> Result<&Table2Row, ArcFlashError> {
+        self.table_2
+            .get(&ec)
+            .ok_or(invalid(2, None, None, ec, None))
+    }
+
+    /// Table 3, 4 or 5 row (for 600 V, 2700 V or 14 300 V) for electrode configuration `ec`.
+    pub fn table_3_4_5_row(
+        &self,
+        ec: ElectrodeConfiguration,
+        v_oc: NominalVoltage,
+    ) -> Result<&Table3_4_5Row, ArcFlashError> {
+        let (table, rows) = match v_oc {
+            NominalVoltage::V600 => (3, &self.table_3),
+            NominalVoltage::V2700 => (4, &self.table_4),
+            NominalVoltage::V14300 => (5, &self.table_5),
+        };
+        rows.get(&ec).ok_or(invalid(table, None, None, ec, None))
+    }
+
+    /// Table 7 row for `enclosure_type` and electrode configuration `ec`.
+    pub fn table_7_row(
+        &self,
+        enclosure_type: EnclosureType,
+        ec: ElectrodeConfiguration,
+    ) -> Result<&Table7Row, ArcFlashError> {
+        self.table_7
+            .get(&enclosure_type)
+            .and_then(|rows| rows.get(&ec))
+            .ok_or(invalid(7, None, Some(enclosure_type), ec, None))
+    }
+
+    /// Reads a set from TOML, and checks it.
+    #[cfg(feature = "serde")]
+    pub fn from_toml(s: &str) -> Result<Self> {
+        let set: Self = toml::from_str(s)?;
+        set.check()?;
+        Ok(set)
+    }
+
+    /// Reads a set from JSON, and checks it.
+    #[cfg(feature = "serde")]
+    pub fn from_json<R: Read>(rdr: R) -> Result<Self> {
+        let set: Self = serde_json::from_reader(rdr)?;
+        set.check()?;
+        Ok(set)
+    }
+
+    #[cfg(feature = "serde")]
+    pub fn to_toml(&self) -> Result<String> {
+        Ok(toml::to_string(self)?)
+    }
+
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+}
+
+fn invalid(
+    table: u8,
+    v_oc: Option<NominalVoltage>,
+    enclosure_type: Option<EnclosureType>,
+    ec: ElectrodeConfiguration,
+    coefficient: Option<usize>,
+) -> ArcFlashError {
+    ArcFlashError::InvalidCoefficients {
+        table,
+        v_oc,
+        enclosure_type,
+        ec,
+        coefficient,
+    }
+}
+
+impl Default for CoefficientSet {
+    fn default() -> Self {
+        Self::ieee_1584_2018()
+    }
+}
@@ -1,4 +1,8 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
 #[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ElectrodeConfiguration {
     VCB,
     VCBB,
@@ -7,15 +11,122 @@ pub enum ElectrodeConfiguration {
     VOA,
 }
 
+impl FromStr for ElectrodeConfiguration {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_uppercase().as_str() {
+            "VCB" => Ok(ElectrodeConfiguration::VCB),
+            "VCBB" => Ok(ElectrodeConfiguration::VCBB),
+            "HCB" => Ok(ElectrodeConfiguration::HCB),
+            "HOA" => Ok(ElectrodeConfiguration::HOA),
+            "VOA" => Ok(ElectrodeConfiguration::VOA),
+            _ => Err(anyhow::format_err!(
+                "invalid electrode configuration {:?}: expected one of VCB, VCBB, HCB, HOA, VOA",
+                s
+            )),
+        }
+    }
+}
+
 #[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EnclosureType {
     Typical,
     Shallow,
 }
 
-#[derive(PartialEq, Eq, Hash, Copy, Clone)]
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NominalVoltage {
     V600,
     V2700,
     V14300,
 }
+
+/// Classes of equipment with typical busbar gaps, enclosure sizes, and working distances, from IEEE 1584-2018
+/// Table 8 and Table 10.
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EquipmentClass {
+    Switchgear15kV,
+    Mcc15kV,
+    Switchgear5kV,
+    /// 5 kV switchgear in a 1143 mm high enclosure.
+    Switchgear5kVTall,
+    Mcc5kV,
+    LvSwitchgear,
+    /// LV MCC with a shallow enclosure (depth <= 203.2 mm).
+    LvMccShallow,
+    /// LV panelboard with a shallow enclosure (depth <= 203.2 mm).
+    LvPanelboardShallow,
+    LvMcc,
+    LvPanelboard,
+    /// Cable junction box with a shallow enclosure (depth <= 203.2 mm).
+    CableJunctionBoxShallow,
+    CableJunctionBox,
+}
+
+impl EquipmentClass {
+    pub const ALL: [EquipmentClass; 12] = [
+        EquipmentClass::Switchgear15kV,
+        EquipmentClass::Mcc15kV,
+        EquipmentClass::Switchgear5kV,
+        EquipmentClass::Switchgear5kVTall,
+        EquipmentClass::Mcc5kV,
+        EquipmentClass::LvSwitchgear,
+        EquipmentClass::LvMccShallow,
+        EquipmentClass::LvPanelboardShallow,
+        EquipmentClass::LvMcc,
+        EquipmentClass::LvPanelboard,
+        EquipmentClass::CableJunctionBoxShallow,
+        EquipmentClass::CableJunctionBox,
+    ];
+
+    /// Name of the class, as in Table 8 and Table 10.
+    pub fn name(&self) -> &'static str {
+        match self {
+            EquipmentClass::Switchgear15kV => "15kV Switchgear",
+            EquipmentClass::Mcc15kV => "15kV MCC",
+            EquipmentClass::Switchgear5kV => "5kV Switchgear",
+            EquipmentClass::Switchgear5kVTall => "5kV Switchgear (2)",
+            EquipmentClass::Mcc5kV => "5kV MCC",
+            EquipmentClass::LvSwitchgear => "LV Switchgear",
+            EquipmentClass::LvMccShallow => "LV MCC (Shallow)",
+            EquipmentClass::LvPanelboardShallow => "LV Panelboard (Shallow)",
+            EquipmentClass::LvMcc => "LV MCC",
+            EquipmentClass::LvPanelboard => "LV Panelboard",
+            EquipmentClass::CableJunctionBoxShallow => "Cable Junction Box (Shallow)",
+            EquipmentClass::CableJunctionBox => "Cable Junction Box",
+        }
+    }
+}
+
+impl FromStr for EquipmentClass {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        EquipmentClass::ALL
+            .into_iter()
+            .find(|class| class.name().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| anyhow::format_err!("invalid equipment class {:?}", s))
+    }
+}
+
+impl Display for EquipmentClass {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Units not provided by `uom`.
+pub mod units {
+    unit! {
+        system: uom::si;
+        quantity: uom::si::radiant_exposure;
+
+        /// 1 cal/cm² = 4.184 J/cm²
+        @calorie_per_square_centimeter: 4.184_E4; "cal/cm²", "calorie per square centimeter",
+            "calories per square centimeter";
+    }
+}
@@ -1,36 +1,56 @@
 // Copyright 2022, Li-aung Yip - https://www.penwatch.net
 // Licensed under the MIT License. Refer LICENSE.txt.
 
-use anyhow::{format_err, Result};
 use std::fmt::{Display, Formatter};
+use std::sync::Arc;
 use uom::fmt::DisplayStyle::Abbreviation;
 use uom::si::electric_potential::kilovolt;
 use uom::si::f64::*;
+use uom::si::frequency::hertz;
 use uom::si::length::inch;
 use uom::si::length::millimeter as mm;
 
+use crate::coefficients::{CoefficientSet, CHECKED, IEEE_1584_2018};
 use crate::common::{ElectrodeConfiguration, EnclosureType};
-use crate::tables::{TABLE_2, TABLE_7};
+use crate::error::ArcFlashError;
+use crate::warning::Warning;
 
 /// Encapsulates physical parameters of equipment that do not change with current (kA) or time (ms).
+///
+/// With the `serde` feature, a cubicle is serialized as the inputs to `Cubicle::new` (see `CubicleData`) and is
+/// validated by `Cubicle::new` when deserialized.
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "CubicleData", try_from = "CubicleData")
+)]
 pub struct Cubicle {
     /// nominal voltage
-    pub v_oc: ElectricPotential,
-    pub ec: ElectrodeConfiguration,
+    pub(crate) v_oc: ElectricPotential,
+    pub(crate) ec: ElectrodeConfiguration,
     /// busbar gap
-    pub g: Length,
+    pub(crate) g: Length,
     /// working distance
-    pub d: Length,
+    pub(crate) d: Length,
+    /// system frequency, if given
+    pub(crate) frequency: Option<Frequency>,
 
-    pub dim: BoxDimensions,
+    pub(crate) dim: BoxDimensions,
     // escf: EnclosureCorrectionFactor,
-    pub enclosure_type: EnclosureType,
+    pub(crate) enclosure_type: EnclosureType,
     pub var_cf: f64,
     pub cf: f64,
     pub(crate) debug: Option<EnclosureDebug>,
-    pub hv: bool,
+    pub(crate) hv: bool,
+    /// Assumptions applied to the cubicle, and conditions where IEEE 1584-2018 advises caution.
+    pub warnings: Vec<Warning>,
+    /// Coefficients of the model, used for `var_cf` and `cf` and by `i_arc` and `e_afb`. Private, so that only a
+    /// checked set can be used; see `with_coefficients`.
+    coefficients: Arc<CoefficientSet>,
 }
 
+#[derive(Clone)]
 pub(crate) struct EnclosureDebug {
     pub(crate) height: Length,
     pub(crate) width: Length,
@@ -38,6 +58,11 @@ pub(crate) struct EnclosureDebug {
 }
 
 impl Cubicle {
+    /// Checks the parameters against the range of the model and calculates the enclosure size correction factor.
+    ///
+    /// The model is for 3-phase AC systems at 50 Hz or 60 Hz, 208 V to 15 kV. Parameters outside the range of the
+    /// model, or that are not finite, are an error. The system frequency is not a parameter of the model and is not
+    /// known here; give it with `with_frequency` (or `CubicleBuilder::frequency`) to have it checked as well.
     pub fn new(
         v_oc: ElectricPotential,
         ec: ElectrodeConfiguration,
@@ -46,8 +71,117 @@ impl Cubicle {
         height: Length,
         width: Length,
         depth: Length,
-    ) -> Result<Self> {
-        let var_cf = Self::calc_var_cf(v_oc, ec);
+    ) -> Result<Self, ArcFlashError> {
+        Self::build(
+            v_oc,
+            ec,
+            g,
+            d,
+            height,
+            width,
+            depth,
+            None,
+            IEEE_1584_2018.clone(),
+        )
+    }
+
+    /// The same cubicle at system frequency `f`, which is checked against the range of the model with the other
+    /// parameters (50 Hz or 60 Hz, the frequencies of the tests the model is fitted to).
+    pub fn with_frequency(self, f: Frequency) -> Result<Self, ArcFlashError> {
+        Self::build(
+            self.v_oc,
+            self.ec,
+            self.g,
+            self.d,
+            self.dim.height,
+            self.dim.width,
+            self.dim.depth,
+            Some(f),
+            self.coefficients,
+        )
+    }
+
+    /// The same cubicle with another set of coefficients (e.g. a set calibrated to new test data), for comparison
+    /// with the published IEEE 1584-2018 values.
+    ///
+    /// The coefficients are checked, and `var_cf` and `cf` are recalculated with them.
+    pub fn with_coefficients(
+        self,
+        coefficients: Arc<CoefficientSet>,
+    ) -> Result<Self, ArcFlashError> {
+        coefficients.check()?;
+        Self::build(
+            self.v_oc,
+            self.ec,
+            self.g,
+            self.d,
+            self.dim.height,
+            self.dim.width,
+            self.dim.depth,
+            self.frequency,
+            coefficients,
+        )
+    }
+
+    /// Coefficients of the model (the published IEEE 1584-2018 set unless set by `with_coefficients`).
+    pub fn coefficients(&self) -> &Arc<CoefficientSet> {
+        &self.coefficients
+    }
+
+    /// Nominal voltage `V_oc`.
+    pub fn v_oc(&self) -> ElectricPotential {
+        self.v_oc
+    }
+
+    /// Electrode configuration.
+    pub fn ec(&self) -> ElectrodeConfiguration {
+        self.ec
+    }
+
+    /// Busbar gap `G`.
+    pub fn g(&self) -> Length {
+        self.g
+    }
+
+    /// Working distance `D`.
+    pub fn d(&self) -> Length {
+        self.d
+    }
+
+    /// System frequency, if given by `with_frequency`.
+    pub fn frequency(&self) -> Option<Frequency> {
+        self.frequency
+    }
+
+    /// Enclosure height, width, and depth.
+    pub fn dim(&self) -> BoxDimensions {
+        self.dim
+    }
+
+    pub fn enclosure_type(&self) -> EnclosureType {
+        self.enclosure_type
+    }
+
+    /// Whether `V_oc` is above 600 V, i.e. the high voltage model (Equations 1, 3, 4, 5, and 16) applies.
+    pub fn hv(&self) -> bool {
+        self.hv
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn build(
+        v_oc: ElectricPotential,
+        ec: ElectrodeConfiguration,
+        g: Length,
+        d: Length,
+        height: Length,
+        width: Length,
+        depth: Length,
+        frequency: Option<Frequency>,
+        coefficients: Arc<CoefficientSet>,
+    ) -> Result<Self, ArcFlashError> {
+        Self::check_model_bounds(v_oc, frequency, g, d, height, width, depth)?;
+
+        let var_cf = Self::calc_var_cf(v_oc, ec, &coefficients);
 
         let enclosure_type = if (v_oc < ElectricPotential::new::<kilovolt>(0.6))
             && (height < Length::new::<mm>(508.0))
@@ -59,32 +193,26 @@ impl Cubicle {
             EnclosureType::Typical
         };
 
-        let (cf, debug) = Self::calc_cf(v_oc, ec, height, width, enclosure_type);
-
-        Self::check_model_bounds(v_oc, g, d, width)?;
+        let (cf, debug) = Self::calc_cf(v_oc, ec, height, width, enclosure_type, &coefficients);
+        let warnings = Self::check_warnings(v_oc, ec, height, width, depth, enclosure_type);
 
-        if cf < 0.0 || cf > 3.0 {
-            return Err(format_err!(
-                "enclosure size correction factor ({}) must be between 0 and 3",
-                cf
-            ));
+        if !(0.0..=3.0).contains(&cf) {
+            return Err(ArcFlashError::CfOutOfRange {
+                cf,
+                min: 0.0,
+                max: 3.0,
+            });
         }
 
-        let hv = if ElectricPotential::new::<kilovolt>(0.600) < v_oc
-            && v_oc <= ElectricPotential::new::<kilovolt>(15.000)
-        {
-            true
-        } else if v_oc <= ElectricPotential::new::<kilovolt>(0.600) {
-            false
-        } else {
-            unreachable!()
-        };
+        // V_oc is within 0.208 kV to 15 kV, from `check_model_bounds`.
+        let hv = v_oc > ElectricPotential::new::<kilovolt>(0.600);
 
         Ok(Self {
             v_oc,
             ec,
             g,
             d,
+            frequency,
             dim: BoxDimensions {
                 height,
                 width,
@@ -95,62 +223,116 @@ impl Cubicle {
             cf,
             debug,
             hv,
+            warnings,
+            coefficients,
         })
     }
 
+    fn check_warnings(
+        v_oc: ElectricPotential,
+        ec: ElectrodeConfiguration,
+        height: Length,
+        width: Length,
+        depth: Length,
+        enclosure_type: EnclosureType,
+    ) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+
+        if v_oc <= ElectricPotential::new::<kilovolt>(0.240) {
+            warnings.push(Warning::LowVoltage240 { v_oc });
+        }
+
+        if ec == ElectrodeConfiguration::HOA || ec == ElectrodeConfiguration::VOA {
+            // Open air configurations HOA / VOA do not require a box size correction factor.
+            return warnings;
+        }
+
+        let small = Length::new::<mm>(508.0);
+        let large = Length::new::<mm>(1244.6);
+
+        if v_oc < ElectricPotential::new::<kilovolt>(0.6)
+            && height < small
+            && width < small
+            && enclosure_type == EnclosureType::Typical
+        {
+            warnings.push(Warning::ShallowEnclosureDepth { depth });
+        }
+        if enclosure_type == EnclosureType::Typical && (height < small || width < small) {
+            warnings.push(Warning::EnclosureSizeIncreased { height, width });
+        }
+        if height > large || width > large {
+            warnings.push(Warning::EnclosureSizeLimited { height, width });
+        }
+
+        warnings
+    }
+
     // ref IEEE 1584-2018 s4.2 "Range of model"
     // Applying the IEEE 1584-2018 model outside these ranges _WILL_ give incorrect results.
     fn check_model_bounds(
         v_oc: ElectricPotential,
+        frequency: Option<Frequency>,
         g: Length,
         d: Length,
+        height: Length,
         width: Length,
-    ) -> Result<()> {
-        // assert 0.208 * kV <= self.V_oc <= 15 * kV
+        depth: Length,
+    ) -> Result<(), ArcFlashError> {
+        let (v_min, v_max) = (
+            ElectricPotential::new::<kilovolt>(0.208),
+            ElectricPotential::new::<kilovolt>(15.0),
+        );
+        if !(v_min <= v_oc && v_oc <= v_max) {
+            return Err(ArcFlashError::VoltageOutOfRange {
+                v_oc,
+                min: v_min,
+                max: v_max,
+            });
+        }
 
-        if v_oc <= ElectricPotential::new::<kilovolt>(0.600) {
-            // low voltage
-            if g < Length::new::<mm>(6.35) {
-                return Err(format_err!(
-                    "busbar gap G must be >= 6.35 mm: {}",
-                    g.into_format_args(mm, Abbreviation)
-                ));
+        // The frequencies of the tests the model is fitted to.
+        if let Some(f) = frequency {
+            if ![50.0, 60.0].contains(&f.get::<hertz>()) {
+                return Err(ArcFlashError::FrequencyOutOfRange { f });
             }
-            if g > Length::new::<mm>(76.2) {
-                return Err(format_err!(
-                    "busbar gap G must be <= 76.2 mm: {}",
-                    g.into_format_args(mm, Abbreviation)
-                ));
+        }
+
+        for (field, value) in [
+            ("d", d),
+            ("height", height),
+            ("width", width),
+            ("depth", depth),
+        ] {
+            if !(value.is_finite() && value > Length::new::<mm>(0.0)) {
+                return Err(ArcFlashError::InvalidLength { field, value });
             }
+        }
+
+        let (g_min, g_max) = if v_oc <= ElectricPotential::new::<kilovolt>(0.600) {
+            // low voltage
+            (Length::new::<mm>(6.35), Length::new::<mm>(76.2))
         } else {
             // high voltage
-            if g < Length::new::<mm>(19.05) {
-                return Err(format_err!(
-                    "busbar gap G must be >= 19.05 mm: {}",
-                    g.into_format_args(mm, Abbreviation)
-                ));
-            }
-            if g > Length::new::<mm>(254.0) {
-                return Err(format_err!(
-                    "busbar gap G must be <= 254 mm: {}",
-                    g.into_format_args(mm, Abbreviation)
-                ));
-            }
+            (Length::new::<mm>(19.05), Length::new::<mm>(254.0))
+        };
+        if !(g_min <= g && g <= g_max) {
+            return Err(ArcFlashError::GapOutOfRange {
+                g,
+                min: g_min,
+                max: g_max,
+            });
         }
-        if d < Length::new::<mm>(305.0) {
-            return Err(format_err!(
-                "working distance D must be >= 305 mm: {}",
-                d.into_format_args(mm, Abbreviation)
-            ));
+
+        let d_min = Length::new::<mm>(305.0);
+        if d < d_min {
+            return Err(ArcFlashError::WorkingDistanceTooSmall { d, min: d_min });
         }
 
         if width < 4.0 * g {
-            return Err(format_err!(
-                "width of enclosure ({}) must be at least four times the busbar gap G (4 * {} = {})",
-                width.into_format_args(mm, Abbreviation),
-                g.into_format_args(mm, Abbreviation),
-                (4.0 * g).into_format_args(mm, Abbreviation)
-            ));
+            return Err(ArcFlashError::EnclosureTooNarrow {
+                width,
+                min: 4.0 * g,
+            });
         }
 
         Ok(())
@@ -158,11 +340,15 @@ impl Cubicle {
 
     // Arcing current variation correction factor.
     // The equation under Equation 2. (Equation 2a?)
-    fn calc_var_cf(v_oc: ElectricPotential, ec: ElectrodeConfiguration) -> f64 {
+    fn calc_var_cf(
+        v_oc: ElectricPotential,
+        ec: ElectrodeConfiguration,
+        coefficients: &CoefficientSet,
+    ) -> f64 {
         // Specifically need V_oc to be in kV for this formula
         let v_oc = v_oc.get::<kilovolt>();
 
-        let k = TABLE_2.get(&ec).unwrap();
+        let k = coefficients.table_2_row(ec).expect(CHECKED);
 
         k.k1 * v_oc.powi(6)
             + k.k2 * v_oc.powi(5)
@@ -180,6 +366,7 @@ impl Cubicle {
         h: Length,
         w: Length,
         enclosure_type: EnclosureType,
+        coefficients: &CoefficientSet,
     ) -> (f64, Option<EnclosureDebug>) {
         if ec == ElectrodeConfiguration::HOA || ec == ElectrodeConfiguration::VOA {
             // Open air configurations HOA / VOA do not require a box size correction factor.
@@ -270,7 +457,7 @@ impl Cubicle {
 
         // Equation 14 / 15
         // key = (self.enclosure_type, self.EC)
-        let b = TABLE_7.get(&(enclosure_type, ec)).unwrap();
+        let b = coefficients.table_7_row(enclosure_type, ec).expect(CHECKED);
         let ees = ees.get::<inch>();
         let x1 = b.b1 * ees.powi(2) + b.b2 * ees + b.b3;
 
@@ -283,12 +470,87 @@ impl Cubicle {
     }
 }
 
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BoxDimensions {
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "height_mm", with = "crate::serde_units::millimeter")
+    )]
     pub height: Length,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "width_mm", with = "crate::serde_units::millimeter")
+    )]
     pub width: Length,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "depth_mm", with = "crate::serde_units::millimeter")
+    )]
     pub depth: Length,
 }
 
+/// Serialized form of a `Cubicle`: voltage in kV and lengths in mm.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct CubicleData {
+    #[serde(rename = "v_oc_kv", with = "crate::serde_units::kilovolt")]
+    pub v_oc: ElectricPotential,
+    pub ec: ElectrodeConfiguration,
+    #[serde(rename = "g_mm", with = "crate::serde_units::millimeter")]
+    pub g: Length,
+    #[serde(rename = "d_mm", with = "crate::serde_units::millimeter")]
+    pub d: Length,
+    pub dim: BoxDimensions,
+    /// System frequency in Hz, omitted if not given.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub frequency_hz: Option<f64>,
+    /// Omitted for the published IEEE 1584-2018 coefficients.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub coefficients: Option<CoefficientSet>,
+}
+
+#[cfg(feature = "serde")]
+impl From<Cubicle> for CubicleData {
+    fn from(c: Cubicle) -> Self {
+        Self {
+            v_oc: c.v_oc,
+            ec: c.ec,
+            g: c.g,
+            d: c.d,
+            dim: c.dim,
+            frequency_hz: c.frequency.map(|f| f.get::<hertz>()),
+            coefficients: (!c.coefficients.is_ieee_1584_2018())
+                .then(|| CoefficientSet::clone(&c.coefficients)),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<CubicleData> for Cubicle {
+    type Error = ArcFlashError;
+
+    fn try_from(data: CubicleData) -> Result<Self, ArcFlashError> {
+        let c = Cubicle::new(
+            data.v_oc,
+            data.ec,
+            data.g,
+            data.d,
+            data.dim.height,
+            data.dim.width,
+            data.dim.depth,
+        )?;
+        let c = match data.frequency_hz {
+            Some(f) => c.with_frequency(Frequency::new::<hertz>(f))?,
+            None => c,
+        };
+        match data.coefficients {
+            Some(coefficients) => c.with_coefficients(Arc::new(coefficients)),
+            None => Ok(c),
+        }
+    }
+}
+
 impl Display for Cubicle {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -332,6 +594,17 @@ Enclosure correction factor
             )?;
         }
 
+        if !self.coefficients.is_ieee_1584_2018() {
+            write!(f, "\n\nCoefficients: {}", self.coefficients.name)?;
+        }
+
+        if !self.warnings.is_empty() {
+            write!(f, "\n\nWarnings:")?;
+            for warning in &self.warnings {
+                write!(f, "\n    {}", warning)?;
+            }
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,240 @@
+use anyhow::{format_err, Result};
+use std::fmt::{Display, Formatter};
+use uom::fmt::DisplayStyle::Abbreviation;
+use uom::si::electric_current::{ampere, kiloampere};
+use uom::si::electric_potential::volt;
+use uom::si::electrical_resistance::ohm;
+use uom::si::f64::{
+    ElectricCurrent, ElectricPotential, ElectricalResistance, Length, Power, RadiantExposure, Time,
+};
+use uom::si::length::millimeter;
+use uom::si::power::kilowatt;
+use uom::si::radiant_exposure::joule_per_square_centimeter;
+use uom::si::time::{millisecond, second};
+
+use crate::common::units::calorie_per_square_centimeter as cal_per_sq_cm;
+
+/// The coefficient of NFPA 70E D.5.1, `E = 0.01 × V × I_arc × T / D²` (cal/cm², with `D` in cm), in terms of the
+/// arc power `P = V × I_arc / 2` at maximum power and in J/cm²: `E = 0.02 × 4.184 × P × T / D²`.
+const NFPA_70E_OPEN_AIR: f64 = 0.02 * 4.184;
+
+/// A DC source (e.g. a battery bank or PV array), as its voltage and bolted fault current. The system resistance is
+/// `R_sys = V / I_bf`.
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub struct DcSource {
+    pub v: ElectricPotential,
+    pub i_bf: ElectricCurrent,
+}
+
+impl DcSource {
+    pub fn r_sys(&self) -> ElectricalResistance {
+        self.v / self.i_bf
+    }
+}
+
+/// Model of the DC arc.
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub enum DcArcModel {
+    /// Maximum power method (Doan, NFPA 70E Annex D.5.1): the arc resistance is taken to equal the system
+    /// resistance, so the arcing current is half the bolted fault current and the arc power is the maximum
+    /// possible, `V × I_bf / 4`. In open air the incident energy is that of NFPA 70E, `E = 0.01 × V × I_arc × T / D²`.
+    MaximumPower,
+    /// Arc resistance of Stokes & Oppenlander, `R_arc = (20 + 0.534 × z_g) / I_arc^0.88`, with the gap `z_g` in mm.
+    StokesOppenlander { gap: Length },
+    /// Arc resistance of the form `R_arc = k / I_arc^n` (ohms, with `I_arc` in A), with coefficients fitted to test
+    /// data for the electrode gap (e.g. Paukert's). `n` must be less than 1.
+    PowerLaw { k: f64, n: f64 },
+}
+
+/// Enclosure around a DC arc, for the incident energy model of Wilkins et al., `E = k × P × t / (a² + D²)`.
+///
+/// In open air this is the NFPA 70E equation, `0.02 × P × t / D²` in cal/cm² with `D` in cm, about 5 % more than a
+/// spherical radiator, `P × t / (4 π D²)`. In a box, the constants `a` and `k` account for the energy reflected
+/// towards the opening.
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub enum DcEnclosure {
+    OpenAir,
+    /// LV switchgear, 508 × 508 × 508 mm.
+    LvSwitchgear,
+    /// LV MCCs and panelboards, 355.6 × 304.8 × ≤203.2 mm.
+    LvMccPanelboard,
+    /// MV switchgear, 1143 × 762 × 762 mm.
+    MvSwitchgear,
+    /// Other constants for the box model.
+    Custom {
+        a: Length,
+        k: f64,
+    },
+}
+
+impl DcEnclosure {
+    /// The constants `a` and `k`.
+    pub fn constants(&self) -> (Length, f64) {
+        let mm = Length::new::<millimeter>;
+        match self {
+            DcEnclosure::OpenAir => (mm(0.0), NFPA_70E_OPEN_AIR),
+            // R. Wilkins, "Simple improved equations for arc flash hazard analysis", IEEE Electrical Safety Forum,
+            // 2004, as tabulated for DC arcs in a box by R. F. Ammerman et al., "DC-Arc Models and Incident-Energy
+            // Calculations", IEEE Trans. Ind. Appl., vol. 46, no. 5, 2010.
+            DcEnclosure::LvSwitchgear => (mm(100.0), 0.312),
+            DcEnclosure::LvMccPanelboard => (mm(50.0), 0.327),
+            DcEnclosure::MvSwitchgear => (mm(180.0), 0.209),
+            DcEnclosure::Custom { a, k } => (*a, *k),
+        }
+    }
+}
+
+/// Results of a DC arc flash calculation.
+#[derive(Clone, Debug)]
+pub struct DcArcFlash {
+    pub model: DcArcModel,
+    pub enclosure: DcEnclosure,
+    pub i_arc: ElectricCurrent,
+    pub r_arc: ElectricalResistance,
+    pub p_arc: Power,
+    pub t_arc: Time,
+    pub e: RadiantExposure,
+    pub afb: Length,
+}
+
+impl DcArcFlash {
+    /// Calculate the incident energy at distance `d`, rather than at the working distance.
+    pub fn e_at_distance(&self, d: Length) -> RadiantExposure {
+        let (a, k) = self.enclosure.constants();
+        k * self.p_arc * self.t_arc / (a * a + d * d)
+    }
+
+    /// Calculate the distance at which the incident energy falls to `e_b`. This is zero if the incident energy is
+    /// below `e_b` at all distances.
+    pub fn distance_for_e(&self, e_b: RadiantExposure) -> Length {
+        let (a, k) = self.enclosure.constants();
+        let d2 = k * self.p_arc * self.t_arc / e_b - a * a;
+        if d2.value > 0.0 {
+            d2.sqrt()
+        } else {
+            Length::new::<millimeter>(0.0)
+        }
+    }
+}
+
+/// Calculate the arcing current and arc resistance of a DC arc from `source`.
+///
+/// For the iterative models, the arcing current is found from `I_arc = V / (R_sys + R_arc(I_arc))`.
+pub fn dc_arc_current(
+    source: &DcSource,
+    model: DcArcModel,
+) -> Result<(ElectricCurrent, ElectricalResistance)> {
+    let v = source.v.get::<volt>();
+    let i_bf = source.i_bf.get::<ampere>();
+    if !(v > 0.0 && v.is_finite() && i_bf > 0.0 && i_bf.is_finite()) {
+        return Err(format_err!(
+            "DC voltage and bolted fault current must be positive"
+        ));
+    }
+    match model {
+        DcArcModel::StokesOppenlander { gap }
+            if !(gap.get::<millimeter>() >= 0.0 && gap.is_finite()) =>
+        {
+            return Err(format_err!("gap must not be negative"));
+        }
+        DcArcModel::PowerLaw { k, n } if !(k > 0.0 && (0.0..1.0).contains(&n)) => {
+            return Err(format_err!(
+                "arc resistance coefficients must satisfy k > 0 and 0 <= n < 1: k = {}, n = {}",
+                k,
+                n
+            ));
+        }
+        _ => {}
+    }
+
+    let r_sys = v / i_bf;
+    let (i_arc, r_arc) = match model {
+        DcArcModel::MaximumPower => (i_bf / 2.0, r_sys),
+        DcArcModel::StokesOppenlander { gap } => {
+            let k = 20.0 + 0.534 * gap.get::<millimeter>();
+            iterate_arc_current(v, r_sys, i_bf, |i| k / i.powf(0.88))
+        }
+        DcArcModel::PowerLaw { k, n } => iterate_arc_current(v, r_sys, i_bf, |i| k / i.powf(n)),
+    };
+
+    Ok((
+        ElectricCurrent::new::<ampere>(i_arc),
+        ElectricalResistance::new::<ohm>(r_arc),
+    ))
+}
+
+/// Calculate the arcing current, incident energy at working distance `d`, and AFB (1.2 cal/cm²) for a DC arc that
+/// lasts `t_arc`. The arcing current is that of `dc_arc_current`.
+pub fn dc_arc_flash(
+    source: &DcSource,
+    model: DcArcModel,
+    enclosure: DcEnclosure,
+    t_arc: Time,
+    d: Length,
+) -> Result<DcArcFlash> {
+    let (i_arc, r_arc) = dc_arc_current(source, model)?;
+    if !(t_arc.get::<second>() >= 0.0 && t_arc.is_finite() && d.get::<millimeter>() > 0.0) {
+        return Err(format_err!(
+            "arc duration must not be negative and working distance must be positive"
+        ));
+    }
+    let (a, k) = enclosure.constants();
+    if !(a.get::<millimeter>() >= 0.0 && k > 0.0) {
+        return Err(format_err!("enclosure constants must be positive"));
+    }
+
+    let mut result = DcArcFlash {
+        model,
+        enclosure,
+        i_arc,
+        r_arc,
+        p_arc: i_arc * i_arc * r_arc,
+        t_arc,
+        e: RadiantExposure::new::<joule_per_square_centimeter>(0.0),
+        afb: Length::new::<millimeter>(0.0),
+    };
+    result.e = result.e_at_distance(d);
+    result.afb = result.distance_for_e(RadiantExposure::new::<cal_per_sq_cm>(1.2));
+
+    // Finite inputs far outside any real system (e.g. a current of 1e-300 A) can still overflow.
+    if !(result.i_arc.is_finite() && result.e.is_finite() && result.afb.is_finite()) {
+        return Err(format_err!(
+            "DC arc flash calculation does not give a finite result for these inputs"
+        ));
+    }
+
+    Ok(result)
+}
+
+/// Solve `I × (R_sys + R_arc(I)) = V` for the arcing current, returning it with the arc resistance.
+///
+/// For arc resistances `k / I^n` with `n < 1`, the left-hand side increases from 0 at `I = 0` to more than `V` at
+/// `I = I_bf`, so there is one solution, found by bisection.
+fn iterate_arc_current(v: f64, r_sys: f64, i_bf: f64, r_arc: impl Fn(f64) -> f64) -> (f64, f64) {
+    let (mut lo, mut hi) = (0.0, i_bf);
+    for _ in 0..200 {
+        let mid = (lo + hi) / 2.0;
+        if mid * (r_sys + r_arc(mid)) < v {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    let i_arc = (lo + hi) / 2.0;
+    (i_arc, r_arc(i_arc))
+}
+
+impl Display for DcArcFlash {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "I_arc = {}, P_arc = {}, T_arc = {}, E = {}, AFB = {}",
+            self.i_arc.into_format_args(kiloampere, Abbreviation),
+            self.p_arc.into_format_args(kilowatt, Abbreviation),
+            self.t_arc.into_format_args(millisecond, Abbreviation),
+            self.e
+                .into_format_args(joule_per_square_centimeter, Abbreviation),
+            self.afb.into_format_args(millimeter, Abbreviation)
+        )
+    }
+}
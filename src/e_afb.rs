@@ -1,15 +1,23 @@
 use std::fmt::{Display, Formatter};
 use uom::fmt::DisplayStyle::Abbreviation;
-use uom::si::f64::{Length, RadiantExposure, Time};
+use uom::si::f64::{ElectricPotential, Length, RadiantExposure, Time};
 use uom::si::length::millimeter;
 use uom::si::radiant_exposure::joule_per_square_centimeter;
 use uom::si::time::millisecond;
 
 use crate::common::NominalVoltage;
 use crate::cubicle::Cubicle;
-use crate::equations::{intermediate_afb_from_e, intermediate_e, interpolate};
+use crate::equations::{
+    distance_exponent, intermediate_afb_from_e, intermediate_distance_for_e, intermediate_e,
+    intermediate_e_at_distance, interpolate,
+};
+use crate::error::ArcFlashError;
 use crate::i_arc::IArc;
+use crate::warning::{e_afb_warnings, Warning};
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "range", rename_all = "snake_case"))]
+#[derive(Clone)]
 pub enum EAfb {
     HighVoltage(EAfbHV),
     LowVoltage(EAfbLV),
@@ -54,30 +62,232 @@ impl EAfb {
             EAfb::LowVoltage(e_afb) => e_afb.e,
         }
     }
+
+    /// The cubicle values that the result is scaled to other distances with.
+    pub fn basis(&self) -> &DistanceBasis {
+        match self {
+            EAfb::HighVoltage(e_afb) => &e_afb.basis,
+            EAfb::LowVoltage(e_afb) => &e_afb.basis,
+        }
+    }
+
+    /// Calculate the incident energy at distance `d`, rather than at the working distance of the cubicle.
+    ///
+    /// For HV, the intermediate energies at 600, 2700, and 14300 V are calculated at distance `d` and then
+    /// interpolated. `d` must be positive and finite.
+    pub fn e_at_distance(&self, d: Length) -> Result<RadiantExposure, ArcFlashError> {
+        check_distance(d)?;
+        let basis = self.basis();
+        let at_d = |v_oc, e| intermediate_e_at_distance(basis.d, basis.k12(v_oc), e, d);
+        match self {
+            EAfb::HighVoltage(e_afb) => interpolate!(
+                basis.v_oc,
+                at_d(NominalVoltage::V600, e_afb.e_600),
+                at_d(NominalVoltage::V2700, e_afb.e_2700),
+                at_d(NominalVoltage::V14300, e_afb.e_14300)
+            ),
+            EAfb::LowVoltage(e_afb) => Ok(at_d(NominalVoltage::V600, e_afb.e)),
+        }
+    }
+
+    /// Calculate the distance at which the incident energy falls to `e_b`.
+    ///
+    /// This is the arc flash boundary for a threshold energy other than 1.2 cal/cm², e.g. the 4 cal/cm² or
+    /// 8 cal/cm² boundary. For HV, the intermediate distances at 600, 2700, and 14300 V are interpolated,
+    /// as for the AFB. `e_b` must be positive and finite.
+    pub fn distance_for_e(&self, e_b: RadiantExposure) -> Result<Length, ArcFlashError> {
+        check_energy(e_b)?;
+        let basis = self.basis();
+        let for_e = |v_oc, e| intermediate_distance_for_e(basis.d, basis.k12(v_oc), e, e_b);
+        match self {
+            EAfb::HighVoltage(e_afb) => interpolate!(
+                basis.v_oc,
+                for_e(NominalVoltage::V600, e_afb.e_600),
+                for_e(NominalVoltage::V2700, e_afb.e_2700),
+                for_e(NominalVoltage::V14300, e_afb.e_14300)
+            ),
+            EAfb::LowVoltage(e_afb) => Ok(for_e(NominalVoltage::V600, e_afb.e)),
+        }
+    }
+}
+
+/// Checks a distance at which incident energy is calculated, e.g. by `EAfb::e_at_distance`.
+pub(crate) fn check_distance(d: Length) -> Result<(), ArcFlashError> {
+    if d.is_finite() && d > Length::new::<millimeter>(0.0) {
+        Ok(())
+    } else {
+        Err(ArcFlashError::InvalidLength {
+            field: "d",
+            value: d,
+        })
+    }
+}
+
+/// Checks a threshold incident energy, e.g. of `EAfb::distance_for_e`.
+pub(crate) fn check_energy(e_b: RadiantExposure) -> Result<(), ArcFlashError> {
+    if e_b.is_finite() && e_b > RadiantExposure::new::<joule_per_square_centimeter>(0.0) {
+        Ok(())
+    } else {
+        Err(ArcFlashError::InvalidEnergy {
+            field: "e_b",
+            value: e_b,
+        })
+    }
+}
+
+/// The values of the cubicle that a result was calculated for that are needed to scale the result to other
+/// distances: the nominal voltage (for the interpolation of HV results), the working distance, and the distance
+/// exponents `k12` (Tables 3 to 5) at 600, 2700 and 14300 V.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub struct DistanceBasis {
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "v_oc_kv", with = "crate::serde_units::kilovolt")
+    )]
+    pub v_oc: ElectricPotential,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "d_mm", with = "crate::serde_units::millimeter")
+    )]
+    pub d: Length,
+    pub k12: [f64; 3],
+}
+
+impl DistanceBasis {
+    pub fn new(c: &Cubicle) -> Self {
+        Self {
+            v_oc: c.v_oc,
+            d: c.d,
+            k12: [
+                distance_exponent(c, NominalVoltage::V600),
+                distance_exponent(c, NominalVoltage::V2700),
+                distance_exponent(c, NominalVoltage::V14300),
+            ],
+        }
+    }
+
+    fn k12(&self, v_oc: NominalVoltage) -> f64 {
+        match v_oc {
+            NominalVoltage::V600 => self.k12[0],
+            NominalVoltage::V2700 => self.k12[1],
+            NominalVoltage::V14300 => self.k12[2],
+        }
+    }
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone)]
 pub struct EAfbHV {
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "t_arc_ms", with = "crate::serde_units::millisecond")
+    )]
     pub t_arc: Time,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "afb_14300_mm", with = "crate::serde_units::millimeter")
+    )]
     pub afb_14300: Length,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "afb_2700_mm", with = "crate::serde_units::millimeter")
+    )]
     pub afb_2700: Length,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "afb_600_mm", with = "crate::serde_units::millimeter")
+    )]
     pub afb_600: Length,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "afb_mm", with = "crate::serde_units::millimeter")
+    )]
     pub afb: Length,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "e_14300_j_per_cm2",
+            with = "crate::serde_units::joule_per_square_centimeter"
+        )
+    )]
     pub e_14300: RadiantExposure,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "e_2700_j_per_cm2",
+            with = "crate::serde_units::joule_per_square_centimeter"
+        )
+    )]
     pub e_2700: RadiantExposure,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "e_600_j_per_cm2",
+            with = "crate::serde_units::joule_per_square_centimeter"
+        )
+    )]
     pub e_600: RadiantExposure,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "e_j_per_cm2",
+            with = "crate::serde_units::joule_per_square_centimeter"
+        )
+    )]
     pub e: RadiantExposure,
+    /// Values of the cubicle for `EAfb::e_at_distance` and `EAfb::distance_for_e`.
+    pub basis: DistanceBasis,
+    /// Warnings for this result; see `Warning`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub warnings: Vec<Warning>,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone)]
 pub struct EAfbLV {
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "t_arc_ms", with = "crate::serde_units::millisecond")
+    )]
     pub t_arc: Time,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "afb_mm", with = "crate::serde_units::millimeter")
+    )]
     pub afb: Length,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "e_j_per_cm2",
+            with = "crate::serde_units::joule_per_square_centimeter"
+        )
+    )]
     pub e: RadiantExposure,
+    /// Values of the cubicle for `EAfb::e_at_distance` and `EAfb::distance_for_e`.
+    pub basis: DistanceBasis,
+    /// Warnings for this result; see `Warning`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub warnings: Vec<Warning>,
 }
 
 /// Calculate the incident energy and Arc Flash Boundary (AFB).
+///
+/// # Panics
+///
+/// If `i_arc` was not calculated for `c` (a HV arcing current with a LV cubicle, or vice versa); see `try_e_afb`.
 pub fn e_afb(c: &Cubicle, i_arc: IArc, t_arc: Time) -> EAfb {
-    // if c.hv {
-    match i_arc {
+    try_e_afb(c, i_arc, t_arc).expect("arcing current calculated for the cubicle")
+}
+
+/// Calculate the incident energy and Arc Flash Boundary (AFB), as for `e_afb`, with an arcing current that was not
+/// calculated for `c` (a HV arcing current with a LV cubicle, or vice versa) being an error.
+pub fn try_e_afb(c: &Cubicle, i_arc: IArc, t_arc: Time) -> Result<EAfb, ArcFlashError> {
+    if matches!(i_arc, IArc::HighVoltage(_)) != c.hv {
+        return Err(ArcFlashError::MismatchedResult { v_oc: c.v_oc });
+    }
+
+    let e_afb = match i_arc {
         IArc::HighVoltage(i_arc) => {
             // Max
             let e_600 = intermediate_e(
@@ -113,11 +323,13 @@ pub fn e_afb(c: &Cubicle, i_arc: IArc, t_arc: Time) -> EAfb {
                 afb_14300,
                 afb_2700,
                 afb_600,
-                afb: interpolate!(c, afb_600, afb_2700, afb_14300),
+                afb: interpolate!(c.v_oc, afb_600, afb_2700, afb_14300)?,
                 e_14300,
                 e_2700,
                 e_600,
-                e: interpolate!(c, e_600, e_2700, e_14300),
+                e: interpolate!(c.v_oc, e_600, e_2700, e_14300)?,
+                basis: DistanceBasis::new(c),
+                warnings: e_afb_warnings(c, t_arc),
             })
         }
         IArc::LowVoltage(i_arc) => {
@@ -134,9 +346,13 @@ pub fn e_afb(c: &Cubicle, i_arc: IArc, t_arc: Time) -> EAfb {
                 t_arc,
                 e,
                 afb: intermediate_afb_from_e(c, NominalVoltage::V600, e),
+                basis: DistanceBasis::new(c),
+                warnings: e_afb_warnings(c, t_arc),
             })
         }
-    }
+    };
+
+    Ok(e_afb)
 }
 
 impl Display for EAfb {
@@ -0,0 +1,117 @@
+use anyhow::Result;
+use std::fmt::{Display, Formatter};
+use uom::fmt::DisplayStyle::Abbreviation;
+use uom::si::electric_current::kiloampere;
+use uom::si::f64::{ElectricCurrent, Length, RadiantExposure, Time};
+use uom::si::length::millimeter;
+use uom::si::radiant_exposure::joule_per_square_centimeter;
+use uom::si::time::millisecond;
+
+use crate::cubicle::Cubicle;
+use crate::ieee2002::{Device2002, DeviceEquation, Equipment2002, Grounding, Inputs2002};
+use crate::method::{evaluate_method, ArcFlashMethod, Ieee2002, Ieee2018};
+use crate::protection::ProtectiveDevice;
+
+/// Edition of IEEE 1584 to calculate with.
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
+pub enum Edition {
+    Ieee2018,
+    /// IEEE 1584-2002, which also needs the type of equipment (for the distance exponent) and the system grounding.
+    /// With a `device`, its equation is used where the bolted fault current is within its ranges (see `Ieee2002`).
+    Ieee2002 {
+        equipment: Equipment2002,
+        grounding: Grounding,
+        device: Option<Device2002>,
+    },
+}
+
+impl Display for Edition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Edition::Ieee2018 => write!(f, "IEEE 1584-2018"),
+            Edition::Ieee2002 { .. } => write!(f, "IEEE 1584-2002"),
+        }
+    }
+}
+
+/// The governing result of a calculation by one edition, for comparison between editions.
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub struct EditionResult {
+    pub edition: Edition,
+    /// The arcing current of the governing case (full or reduced).
+    pub i_arc: ElectricCurrent,
+    pub t_arc: Time,
+    pub e: RadiantExposure,
+    pub afb: Length,
+    /// True if the clearing time was limited to the maximum arc duration.
+    pub capped: bool,
+}
+
+/// Calculate the incident energy and AFB for cubicle `c` by `edition`, with the clearing time from `device`, and
+/// return the governing case.
+///
+/// For IEEE 1584-2018 this is the worst of the full and reduced arcing current cases. For IEEE 1584-2002 below 1 kV
+/// it is the worse of the arcing current and 85 % of the arcing current (s5.2), each with its own clearing time.
+/// `max_arc_duration` is as for `Scenario::max_arc_duration`.
+pub fn evaluate_edition(
+    edition: Edition,
+    c: &Cubicle,
+    i_bf: ElectricCurrent,
+    device: &dyn ProtectiveDevice,
+    max_arc_duration: Option<Time>,
+) -> Result<EditionResult> {
+    let method: Box<dyn ArcFlashMethod + '_> = match edition {
+        Edition::Ieee2018 => Box::new(Ieee2018 { cubicle: c }),
+        Edition::Ieee2002 {
+            equipment,
+            grounding,
+            device,
+        } => Box::new(Ieee2002 {
+            inputs: Inputs2002::from_cubicle(c, equipment, grounding),
+            device_equation: device
+                .map(|device| DeviceEquation::device(device, c.v_oc))
+                .transpose()?,
+        }),
+    };
+    let evaluation = evaluate_method(method.as_ref(), i_bf, device, max_arc_duration)?;
+    let case = evaluation.governing();
+
+    Ok(EditionResult {
+        edition,
+        i_arc: case.result.i_arc(),
+        t_arc: case.result.t_arc(),
+        e: case.result.e(),
+        afb: case.result.afb(),
+        capped: case.capped,
+    })
+}
+
+/// Calculate cubicle `c` by each of `editions`, for comparison side by side.
+pub fn compare_editions(
+    editions: &[Edition],
+    c: &Cubicle,
+    i_bf: ElectricCurrent,
+    device: &dyn ProtectiveDevice,
+    max_arc_duration: Option<Time>,
+) -> Vec<Result<EditionResult>> {
+    editions
+        .iter()
+        .map(|edition| evaluate_edition(*edition, c, i_bf, device, max_arc_duration))
+        .collect()
+}
+
+impl Display for EditionResult {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: I_arc = {}, T_arc = {}{}, E = {}, AFB = {}",
+            self.edition,
+            self.i_arc.into_format_args(kiloampere, Abbreviation),
+            self.t_arc.into_format_args(millisecond, Abbreviation),
+            if self.capped { " (capped)" } else { "" },
+            self.e
+                .into_format_args(joule_per_square_centimeter, Abbreviation),
+            self.afb.into_format_args(millimeter, Abbreviation)
+        )
+    }
+}
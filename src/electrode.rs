@@ -0,0 +1,177 @@
+use anyhow::Result;
+use std::fmt::{Display, Formatter};
+use uom::fmt::DisplayStyle::Abbreviation;
+use uom::si::f64::{ElectricCurrent, RadiantExposure};
+use uom::si::radiant_exposure::joule_per_square_centimeter;
+
+use crate::common::ElectrodeConfiguration;
+use crate::cubicle::Cubicle;
+use crate::scenario::{evaluate_worst_case, ClearingTimeSource, WorstCase};
+
+/// Orientation of the conductors (busbars) at the point of the arc.
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BusOrientation {
+    Vertical,
+    Horizontal,
+}
+
+/// Location of the arc relative to a breaker or fuse in the equipment.
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DeviceSide {
+    /// On the line side, where the conductors terminate at the device terminals.
+    LineSide,
+    LoadSide,
+}
+
+/// Physical attributes of the equipment that determine the electrode configuration.
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EquipmentAttributes {
+    pub orientation: BusOrientation,
+    /// True if the conductors terminate in an insulating barrier.
+    pub insulating_barrier: bool,
+    /// True if the conductors are in an enclosure (metal box), false if in open air.
+    pub enclosed: bool,
+    /// Location relative to a breaker or fuse, if any.
+    pub side: Option<DeviceSide>,
+}
+
+impl EquipmentAttributes {
+    /// The recommended electrode configuration, following the descriptions of the configurations in IEEE 1584-2018.
+    ///
+    /// * In open air, vertical conductors are VOA and horizontal conductors are HOA.
+    /// * In an enclosure, horizontal conductors are HCB.
+    /// * In an enclosure, vertical conductors that terminate in an insulating barrier, or at the line side
+    ///   terminals of a breaker or fuse, are VCBB. Otherwise they are VCB.
+    pub fn electrode_configuration(&self) -> ElectrodeConfiguration {
+        use ElectrodeConfiguration::*;
+
+        match (self.enclosed, self.orientation) {
+            (false, BusOrientation::Vertical) => VOA,
+            (false, BusOrientation::Horizontal) => HOA,
+            (true, BusOrientation::Horizontal) => HCB,
+            (true, BusOrientation::Vertical) => {
+                if self.insulating_barrier || self.side == Some(DeviceSide::LineSide) {
+                    VCBB
+                } else {
+                    VCB
+                }
+            }
+        }
+    }
+
+    /// The electrode configurations that are plausible for the equipment: VCB, VCBB and HCB in an enclosure, or
+    /// VOA and HOA in open air.
+    pub fn plausible_configurations(&self) -> Vec<ElectrodeConfiguration> {
+        use ElectrodeConfiguration::*;
+
+        if self.enclosed {
+            vec![VCB, VCBB, HCB]
+        } else {
+            vec![VOA, HOA]
+        }
+    }
+}
+
+/// Results for each plausible electrode configuration, see `sensitivity`.
+pub struct Sensitivity {
+    pub recommended: ElectrodeConfiguration,
+    pub results: Vec<(ElectrodeConfiguration, Result<WorstCase>)>,
+}
+
+impl Sensitivity {
+    fn governing_e(&self) -> impl Iterator<Item = (ElectrodeConfiguration, RadiantExposure)> + '_ {
+        self.results.iter().filter_map(|(ec, result)| {
+            result
+                .as_ref()
+                .ok()
+                .map(|worst| (*ec, worst.governing().e_afb.e()))
+        })
+    }
+
+    /// The result for the recommended electrode configuration.
+    pub fn recommended(&self) -> Option<&Result<WorstCase>> {
+        self.results
+            .iter()
+            .find(|(ec, _)| *ec == self.recommended)
+            .map(|(_, result)| result)
+    }
+
+    /// The electrode configuration with the highest incident energy (of the governing case).
+    pub fn max(&self) -> Option<(ElectrodeConfiguration, RadiantExposure)> {
+        self.governing_e()
+            .reduce(|a, b| if b.1 > a.1 { b } else { a })
+    }
+
+    /// The electrode configuration with the lowest incident energy (of the governing case).
+    pub fn min(&self) -> Option<(ElectrodeConfiguration, RadiantExposure)> {
+        self.governing_e()
+            .reduce(|a, b| if b.1 < a.1 { b } else { a })
+    }
+
+    /// The difference between the highest and lowest incident energies.
+    pub fn spread(&self) -> Option<RadiantExposure> {
+        Some(self.max()?.1 - self.min()?.1)
+    }
+}
+
+/// Calculate the full and reduced arcing current cases for each plausible electrode configuration of the
+/// equipment, with the other parameters of cubicle `c`.
+///
+/// A configuration that is outside the range of the model (e.g. because of the busbar gap) gives an error for that
+/// configuration only.
+pub fn sensitivity(
+    c: &Cubicle,
+    attributes: &EquipmentAttributes,
+    i_bf: ElectricCurrent,
+    clearing: &dyn ClearingTimeSource,
+) -> Sensitivity {
+    let results = attributes
+        .plausible_configurations()
+        .into_iter()
+        .map(|ec| {
+            let result = Cubicle::new(c.v_oc, ec, c.g, c.d, c.dim.height, c.dim.width, c.dim.depth)
+                .and_then(|cubicle| cubicle.with_coefficients(c.coefficients().clone()))
+                .map_err(anyhow::Error::from)
+                .and_then(|c| evaluate_worst_case(&c, i_bf, clearing));
+            (ec, result)
+        })
+        .collect();
+
+    Sensitivity {
+        recommended: attributes.electrode_configuration(),
+        results,
+    }
+}
+
+impl Display for Sensitivity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Recommended EC = {:?}", self.recommended)?;
+        for (ec, result) in &self.results {
+            match result {
+                Ok(worst) => write!(
+                    f,
+                    "\n{:?}: E = {} ({:?})",
+                    ec,
+                    worst
+                        .governing()
+                        .e_afb
+                        .e()
+                        .into_format_args(joule_per_square_centimeter, Abbreviation),
+                    worst.governing
+                )?,
+                Err(err) => write!(f, "\n{:?}: {}", ec, err)?,
+            }
+        }
+        if let Some(spread) = self.spread() {
+            write!(
+                f,
+                "\nSpread = {}",
+                spread.into_format_args(joule_per_square_centimeter, Abbreviation)
+            )?;
+        }
+        Ok(())
+    }
+}
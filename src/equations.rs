@@ -8,9 +8,10 @@ use uom::si::length::millimeter as mm;
 use uom::si::radiant_exposure::joule_per_square_centimeter;
 use uom::si::time::millisecond as ms;
 
+use crate::coefficients::CHECKED;
 use crate::common::NominalVoltage;
 use crate::cubicle::Cubicle;
-use crate::tables::{Table3_4_5Row, TABLE_1, TABLE_3, TABLE_4, TABLE_5};
+use crate::tables::Table3_4_5Row;
 
 // Equation 1
 pub fn i_arc_intermediate(
@@ -27,7 +28,7 @@ pub fn i_arc_intermediate(
     let i_bf = i_bf.get::<kiloampere>();
     let g = c.g.get::<mm>();
 
-    let k = TABLE_1.get(&(c.ec, v_oc)).unwrap();
+    let k = c.coefficients().table_1_row(c.ec, v_oc).expect(CHECKED);
 
     let x1 = k.k1 + k.k2 * f64::log10(i_bf) + k.k3 * f64::log10(g);
 
@@ -72,22 +73,14 @@ pub fn intermediate_e(
     let i_arc: f64 = i_arc.get::<kiloampere>();
     let i_bf: f64 = i_bf.get::<kiloampere>();
     let t: f64 = t.get::<ms>();
-    let i_arc_600: Option<f64> = if i_arc_600.is_none() {
-        None
-    } else {
-        Some(i_arc_600.unwrap().get::<kiloampere>())
-    };
+    let i_arc_600: Option<f64> = i_arc_600.map(|i_arc_600| i_arc_600.get::<kiloampere>());
     let g = c.g.get::<mm>();
     let cf = c.cf;
     let d = c.d.get::<mm>();
 
     // assert (V_oc <= 0.6 * kV) or (V_oc in (0.6 * kV, 2.7 * kV, 14.3 * kV,))
 
-    let k: &Table3_4_5Row = match v_oc {
-        NominalVoltage::V600 => TABLE_3.get(&c.ec).unwrap(),
-        NominalVoltage::V2700 => TABLE_4.get(&c.ec).unwrap(),
-        NominalVoltage::V14300 => TABLE_5.get(&c.ec).unwrap(),
-    };
+    let k: &Table3_4_5Row = c.coefficients().table_3_4_5_row(c.ec, v_oc).expect(CHECKED);
     // let k = if V_oc <= 0.6 * kV {
     //     TABLE_3[c.EC]
     // } else if V_oc == 2.7 * kV {
@@ -193,53 +186,81 @@ pub fn intermediate_e(
 // 1.2 cal/cm² × 4.184 J/cal = 5.0208 J/cm²
 // 50 / 12.552 * 5.0208 = 20 (exact)
 pub fn intermediate_afb_from_e(c: &Cubicle, v_oc: NominalVoltage, e: RadiantExposure) -> Length {
-    // assert V_oc.check('[electric_potential]')
-    // assert E.check('[energy]/[area]')
+    intermediate_distance_for_e(
+        c.d,
+        distance_exponent(c, v_oc),
+        e,
+        RadiantExposure::new::<joule_per_square_centimeter>(E_AFB),
+    )
+}
 
-    // _V_oc = V_oc.m_as(kV)
-    let e = e.get::<joule_per_square_centimeter>();
-    let d = c.d.get::<mm>();
+/// Incident energy at the arc flash boundary: 1.2 cal/cm² × 4.184 J/cal = 5.0208 J/cm²
+pub const E_AFB: f64 = 5.0208;
 
-    // assert (V_oc <= 0.6 * kV) or (V_oc in (0.6 * kV, 2.7 * kV, 14.3 * kV,))
+pub fn distance_exponent(c: &Cubicle, v_oc: NominalVoltage) -> f64 {
+    let k: &Table3_4_5Row = c.coefficients().table_3_4_5_row(c.ec, v_oc).expect(CHECKED);
+    k.k12
+}
 
-    // if V_oc <= 0.6 * kV:
-    //     k = table_3[c.EC]
-    // elif V_oc == 2.7 * kV:
-    //     k = table_4[c.EC]
-    // elif V_oc == 14.3 * kV:
-    //     k = table_5[c.EC]
-    // else:
-    //     k = None
-    let k: &Table3_4_5Row = match v_oc {
-        NominalVoltage::V600 => TABLE_3.get(&c.ec).unwrap(),
-        NominalVoltage::V2700 => TABLE_4.get(&c.ec).unwrap(),
-        NominalVoltage::V14300 => TABLE_5.get(&c.ec).unwrap(),
-    };
+// The generalisation of `intermediate_afb_from_e`: the distance D' at which the (intermediate) incident energy `e`
+// at the working distance `d` falls to `e_b`, for distance exponent `k12`.
+//
+//       D' = (E' / F) ^ ( 1 / k12 )
+pub fn intermediate_distance_for_e(
+    d: Length,
+    k12: f64,
+    e: RadiantExposure,
+    e_b: RadiantExposure,
+) -> Length {
+    let e = e.get::<joule_per_square_centimeter>();
+    let e_b = e_b.get::<joule_per_square_centimeter>();
+    let d = d.get::<mm>();
+
+    // After all the explanation, calculation of the (intermediate) distance is simply 2 lines.
+    let f = e / d.powf(k12);
+    let d_b = (e_b / f).powf(1.0 / k12);
+    // assert D_B >= 0
+    Length::new::<mm>(d_b)
+}
+
+// The (intermediate) incident energy at distance `d_x`, given the (intermediate) incident energy `e` at the
+// working distance `d`, for distance exponent `k12`.
+//
+//       E' (at distance D') = F * ( D' ^ k12 )
+pub fn intermediate_e_at_distance(
+    d: Length,
+    k12: f64,
+    e: RadiantExposure,
+    d_x: Length,
+) -> RadiantExposure {
+    let e = e.get::<joule_per_square_centimeter>();
+    let d = d.get::<mm>();
+    let d_x = d_x.get::<mm>();
 
-    // After all the explanation, calculation of the (intermediate) AFB is simply 2 lines.
-    let f = e / d.powf(k.k12);
-    let afb = (5.0208 / f).powf(1.0 / k.k12);
-    // assert AFB >= 0
-    Length::new::<mm>(afb)
+    let f = e / d.powf(k12);
+    RadiantExposure::new::<joule_per_square_centimeter>(f * d_x.powf(k12))
 }
 
+// Interpolates the intermediate values at 600, 2700 and 14300 V at `v_oc` (ElectricPotential). HV values are only
+// defined above 0.6 kV, so a lower `v_oc` (a result used with a cubicle it was not calculated for) is an error.
 macro_rules! interpolate {
-    ($c:expr, $x_600:expr, $x_2700:expr, $x_14300:expr) => {{
-        let v_oc = $c.v_oc.get::<uom::si::electric_potential::kilovolt>();
+    ($v_oc:expr, $x_600:expr, $x_2700:expr, $x_14300:expr) => {{
+        let v_oc: uom::si::f64::ElectricPotential = $v_oc;
+        let kv = v_oc.get::<uom::si::electric_potential::kilovolt>();
 
         // Eq 16, Eq 19, Eq 22
-        let x1 = ((($x_2700 - $x_600) / 2.1) * (v_oc - 2.7)) + $x_2700;
+        let x1 = ((($x_2700 - $x_600) / 2.1) * (kv - 2.7)) + $x_2700;
         // Eq 17, Eq 20, Eq 23
-        let x2 = ((($x_14300 - $x_2700) / 11.6) * (v_oc - 14.3)) + $x_14300;
+        let x2 = ((($x_14300 - $x_2700) / 11.6) * (kv - 14.3)) + $x_14300;
         // Eq 18, Eq 21, Eq 24
-        let x3 = ((x1 * (2.7 - v_oc)) / 2.1) + ((x2 * (v_oc - 0.6)) / 2.1);
+        let x3 = ((x1 * (2.7 - kv)) / 2.1) + ((x2 * (kv - 0.6)) / 2.1);
 
-        if 0.600 < v_oc && v_oc <= 2.7 {
-            x3
-        } else if v_oc > 2.7 {
-            x2
+        if 0.600 < kv && kv <= 2.7 {
+            Ok(x3)
+        } else if kv > 2.7 {
+            Ok(x2)
         } else {
-            unreachable!()
+            Err($crate::error::ArcFlashError::MismatchedResult { v_oc })
         }
     }};
 }
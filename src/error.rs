@@ -0,0 +1,176 @@
+use std::fmt::{Display, Formatter};
+use uom::fmt::DisplayStyle::Abbreviation;
+use uom::si::electric_current::kiloampere;
+use uom::si::electric_potential::kilovolt;
+use uom::si::f64::{ElectricCurrent, ElectricPotential, Frequency, Length, RadiantExposure};
+use uom::si::frequency::hertz;
+use uom::si::length::millimeter as mm;
+use uom::si::radiant_exposure::joule_per_square_centimeter;
+
+use crate::common::{ElectrodeConfiguration, EnclosureType, NominalVoltage};
+
+/// Inputs outside the range of the IEEE 1584-2018 model (ref s4.2 "Range of model"), or coefficients that the model
+/// cannot be evaluated with.
+///
+/// Each variant carries the offending value and the limits that apply to it.
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub enum ArcFlashError {
+    /// Nominal voltage `V_oc` outside 208 V to 15 kV.
+    VoltageOutOfRange {
+        v_oc: ElectricPotential,
+        min: ElectricPotential,
+        max: ElectricPotential,
+    },
+    /// Working distance `D`, or an enclosure dimension, that is not positive and finite. `field` is one of "d",
+    /// "height", "width", or "depth".
+    InvalidLength { field: &'static str, value: Length },
+    /// Incident energy threshold that is not positive and finite, e.g. `e_b` of `EAfb::distance_for_e`.
+    InvalidEnergy {
+        field: &'static str,
+        value: RadiantExposure,
+    },
+    /// Busbar gap `G` outside the range for the voltage (6.35 mm to 76.2 mm for LV, 19.05 mm to 254 mm for HV).
+    GapOutOfRange { g: Length, min: Length, max: Length },
+    /// Working distance `D` less than 305 mm.
+    WorkingDistanceTooSmall { d: Length, min: Length },
+    /// Enclosure width less than four times the busbar gap `G`.
+    EnclosureTooNarrow { width: Length, min: Length },
+    /// Enclosure size correction factor `CF` outside 0 to 3.
+    CfOutOfRange { cf: f64, min: f64, max: f64 },
+    /// Bolted fault current `I_bf` outside the range for the voltage (500 A to 106 kA for LV, 200 A to 65 kA
+    /// for HV).
+    IbfOutOfRange {
+        i_bf: ElectricCurrent,
+        min: ElectricCurrent,
+        max: ElectricCurrent,
+    },
+    /// A result used with a cubicle (or the basis of a result) that it was not calculated for, e.g. a HV arcing
+    /// current with a LV cubicle, where the 600, 2700 and 14300 V values cannot be interpolated at `v_oc`.
+    MismatchedResult { v_oc: ElectricPotential },
+    /// System frequency other than 50 Hz or 60 Hz (see `Cubicle::with_frequency`).
+    FrequencyOutOfRange { f: Frequency },
+    /// A coefficient set (see `CoefficientSet`) that has no row of `table` for the intermediate voltage `v_oc`,
+    /// enclosure type and electrode configuration `ec` (for `coefficient` = `None`), or in which coefficient number
+    /// `coefficient` of that row is not finite. `v_oc` and `enclosure_type` are set only for Tables 1 and 7.
+    InvalidCoefficients {
+        table: u8,
+        v_oc: Option<NominalVoltage>,
+        enclosure_type: Option<EnclosureType>,
+        ec: ElectrodeConfiguration,
+        coefficient: Option<usize>,
+    },
+}
+
+impl ArcFlashError {
+    /// Name of the input field that is out of range, e.g. for highlighting in a user interface.
+    pub fn field(&self) -> &'static str {
+        match self {
+            ArcFlashError::VoltageOutOfRange { .. } => "v_oc",
+            ArcFlashError::InvalidLength { field, .. } => field,
+            ArcFlashError::InvalidEnergy { field, .. } => field,
+            ArcFlashError::GapOutOfRange { .. } => "g",
+            ArcFlashError::WorkingDistanceTooSmall { .. } => "d",
+            ArcFlashError::EnclosureTooNarrow { .. } => "width",
+            ArcFlashError::CfOutOfRange { .. } => "cf",
+            ArcFlashError::IbfOutOfRange { .. } => "i_bf",
+            ArcFlashError::MismatchedResult { .. } => "v_oc",
+            ArcFlashError::FrequencyOutOfRange { .. } => "frequency",
+            ArcFlashError::InvalidCoefficients { .. } => "coefficients",
+        }
+    }
+}
+
+impl Display for ArcFlashError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ArcFlashError::VoltageOutOfRange { v_oc, min, max } => write!(
+                f,
+                "V_oc = {} is outside calculation range {} to {}",
+                v_oc.into_format_args(kilovolt, Abbreviation),
+                min.into_format_args(kilovolt, Abbreviation),
+                max.into_format_args(kilovolt, Abbreviation)
+            ),
+            ArcFlashError::InvalidLength { field, value } => write!(
+                f,
+                "{} must be positive and finite: {}",
+                field,
+                value.into_format_args(mm, Abbreviation)
+            ),
+            ArcFlashError::InvalidEnergy { field, value } => write!(
+                f,
+                "{} must be positive and finite: {}",
+                field,
+                value.into_format_args(joule_per_square_centimeter, Abbreviation)
+            ),
+            ArcFlashError::GapOutOfRange { g, min, max } => {
+                if g < min {
+                    write!(f, "busbar gap G must be >= {} mm: ", min.get::<mm>())?;
+                } else {
+                    write!(f, "busbar gap G must be <= {} mm: ", max.get::<mm>())?;
+                }
+                write!(f, "{}", g.into_format_args(mm, Abbreviation))
+            }
+            ArcFlashError::WorkingDistanceTooSmall { d, min } => write!(
+                f,
+                "working distance D must be >= {} mm: {}",
+                min.get::<mm>(),
+                d.into_format_args(mm, Abbreviation)
+            ),
+            ArcFlashError::EnclosureTooNarrow { width, min } => write!(
+                f,
+                "width of enclosure ({}) must be at least four times the busbar gap G (4 * {} = {})",
+                width.into_format_args(mm, Abbreviation),
+                (*min / 4.0).into_format_args(mm, Abbreviation),
+                min.into_format_args(mm, Abbreviation)
+            ),
+            ArcFlashError::CfOutOfRange { cf, min, max } => write!(
+                f,
+                "enclosure size correction factor ({}) must be between {} and {}",
+                cf, min, max
+            ),
+            ArcFlashError::IbfOutOfRange { i_bf, min, max } => write!(
+                f,
+                "I_bf = {} is outside calculation range {} to {}",
+                i_bf.into_format_args(kiloampere, Abbreviation),
+                min.into_format_args(kiloampere, Abbreviation),
+                max.into_format_args(kiloampere, Abbreviation)
+            ),
+            ArcFlashError::MismatchedResult { v_oc } => write!(
+                f,
+                "result was calculated for another cubicle: it cannot be used at V_oc = {}",
+                v_oc.into_format_args(kilovolt, Abbreviation)
+            ),
+            ArcFlashError::FrequencyOutOfRange { f: frequency } => write!(
+                f,
+                "frequency {} is outside the range of the model (50 Hz or 60 Hz)",
+                frequency.into_format_args(hertz, Abbreviation)
+            ),
+            ArcFlashError::InvalidCoefficients {
+                table,
+                v_oc,
+                enclosure_type,
+                ec,
+                coefficient,
+            } => {
+                let mut row = String::new();
+                if let Some(v_oc) = v_oc {
+                    row += &format!("{:?} ", v_oc);
+                }
+                if let Some(enclosure_type) = enclosure_type {
+                    row += &format!("{:?} ", enclosure_type);
+                }
+                row += &format!("{:?}", ec);
+                match coefficient {
+                    None => write!(f, "Table {} has no row for {}", table, row),
+                    Some(i) => write!(
+                        f,
+                        "Table {} {}: coefficient {} is not finite",
+                        table, row, i
+                    ),
+                }
+            }
+        }
+    }
+}
+
+impl std::error::Error for ArcFlashError {}
@@ -0,0 +1,97 @@
+use anyhow::{format_err, Result};
+use uom::si::electric_potential::volt;
+use uom::si::f64::{ElectricCurrent, ElectricPotential, Power, Time};
+use uom::si::power::kilowatt;
+use uom::si::time::second;
+
+use crate::profile::FaultCurrentProfile;
+
+/// A synchronous generator, for the decrement of its contribution to a three-phase fault at its terminals.
+///
+/// The symmetrical current decays from the subtransient value `I / Xd''` through the transient value `I / Xd'` to
+/// the synchronous value `I / Xd`, where `I` is the rated current:
+///
+/// ```text
+/// i(t) = I × [(1/Xd'' - 1/Xd') × exp(-t/T'') + (1/Xd' - 1/Xd) × exp(-t/T') + 1/Xd]
+/// ```
+///
+/// With exciter field forcing (e.g. a PMG-supported exciter) the current is sustained at `field_forcing` per unit of
+/// rated current instead of decaying to `I / Xd`.
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub struct Generator {
+    /// Rating in kVA.
+    pub kva: f64,
+    /// Rated voltage (phase to phase).
+    pub v: ElectricPotential,
+    /// Subtransient reactance Xd'', per unit on the generator base.
+    pub xd_subtransient: f64,
+    /// Transient reactance Xd', per unit on the generator base.
+    pub xd_transient: f64,
+    /// Synchronous reactance Xd, per unit on the generator base.
+    pub xd: f64,
+    /// Subtransient short-circuit time constant T''.
+    pub t_subtransient: Time,
+    /// Transient short-circuit time constant T'.
+    pub t_transient: Time,
+    /// Sustained short-circuit current with field forcing, per unit of rated current. `None` if the exciter does
+    /// not support the fault current.
+    pub field_forcing: Option<f64>,
+}
+
+impl Generator {
+    fn check(&self) -> Result<()> {
+        let positive = |x: f64| x > 0.0 && x.is_finite();
+
+        if !(positive(self.kva) && positive(self.v.get::<volt>())) {
+            return Err(format_err!("generator rating and voltage must be positive"));
+        }
+        if !(positive(self.xd_subtransient) && positive(self.xd_transient) && positive(self.xd)) {
+            return Err(format_err!("generator reactances must be positive"));
+        }
+        if !(self.xd_subtransient <= self.xd_transient && self.xd_transient <= self.xd) {
+            return Err(format_err!(
+                "generator reactances must satisfy Xd'' <= Xd' <= Xd: {}, {}, {}",
+                self.xd_subtransient,
+                self.xd_transient,
+                self.xd
+            ));
+        }
+        if !(positive(self.t_subtransient.get::<second>())
+            && positive(self.t_transient.get::<second>()))
+        {
+            return Err(format_err!("generator time constants must be positive"));
+        }
+        if let Some(k) = self.field_forcing {
+            if !positive(k) {
+                return Err(format_err!("field forcing must be positive: {} pu", k));
+            }
+        }
+        Ok(())
+    }
+
+    /// Rated current.
+    pub fn i_rated(&self) -> ElectricCurrent {
+        Power::new::<kilowatt>(self.kva) / (3.0_f64.sqrt() * self.v)
+    }
+
+    /// Symmetrical fault current at time `t` after fault inception.
+    pub fn current(&self, t: Time) -> ElectricCurrent {
+        let t = t.max(Time::new::<second>(0.0));
+        let sub = (1.0 / self.xd_subtransient - 1.0 / self.xd_transient)
+            * (-(t / self.t_subtransient).value).exp();
+        let steady = match self.field_forcing {
+            Some(k) => k,
+            None => 1.0 / self.xd,
+        };
+        let trans = (1.0 / self.xd_transient - steady) * (-(t / self.t_transient).value).exp();
+
+        self.i_rated() * (sub + trans + steady)
+    }
+
+    /// The decrement curve, sampled every `step` up to `duration`. The result can be used with `multistep_profile`
+    /// and `profile_clearing_time`.
+    pub fn profile(&self, duration: Time, step: Time) -> Result<FaultCurrentProfile> {
+        self.check()?;
+        FaultCurrentProfile::sample(duration, step, |t| self.current(t))
+    }
+}
@@ -1,15 +1,17 @@
-use anyhow::{format_err, Result};
 use std::fmt::{Display, Formatter};
 use uom::fmt::DisplayStyle::Abbreviation;
 use uom::si::electric_current::kiloampere;
-use uom::si::electric_potential::kilovolt;
-use uom::si::f64::{ElectricCurrent, ElectricPotential};
+use uom::si::f64::ElectricCurrent;
 
 use crate::common::NominalVoltage;
 use crate::cubicle::Cubicle;
 use crate::equations::{i_arc_final_lv, i_arc_intermediate, i_arc_min, interpolate};
+use crate::error::ArcFlashError;
+use crate::warning::{i_arc_warnings, Warning};
 
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "range", rename_all = "snake_case"))]
 pub enum IArc {
     HighVoltage(IArcHV),
     LowVoltage(IArcLV),
@@ -64,21 +66,61 @@ impl IArc {
 }
 
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IArcHV {
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "i_bf_ka", with = "crate::serde_units::kiloampere")
+    )]
     pub i_bf: ElectricCurrent,
     pub reduced: bool,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "i_arc_14300_ka", with = "crate::serde_units::kiloampere")
+    )]
     pub i_arc_14300: ElectricCurrent,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "i_arc_2700_ka", with = "crate::serde_units::kiloampere")
+    )]
     pub i_arc_2700: ElectricCurrent,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "i_arc_600_ka", with = "crate::serde_units::kiloampere")
+    )]
     pub i_arc_600: ElectricCurrent,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "i_arc_ka", with = "crate::serde_units::kiloampere")
+    )]
     pub i_arc: ElectricCurrent,
+    /// Warnings for this result; see `Warning`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub warnings: Vec<Warning>,
 }
 
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IArcLV {
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "i_bf_ka", with = "crate::serde_units::kiloampere")
+    )]
     pub i_bf: ElectricCurrent,
     pub reduced: bool,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "i_arc_600_ka", with = "crate::serde_units::kiloampere")
+    )]
     pub i_arc_600: ElectricCurrent,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "i_arc_ka", with = "crate::serde_units::kiloampere")
+    )]
     pub i_arc: ElectricCurrent,
+    /// Warnings for this result; see `Warning`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub warnings: Vec<Warning>,
 }
 
 /// Calculate the arcing current.
@@ -88,31 +130,26 @@ pub struct IArcLV {
 ///
 /// These are referred to as "full"/"reduced" to avoid confusion with "max"/"min" which mean e.g.
 /// "maximum fault operating scenario" and "minimum fault operating scenario" in context.
-pub fn i_arc(c: &Cubicle, i_bf: ElectricCurrent, reduced: bool) -> Result<IArc> {
-    if (ElectricPotential::new::<kilovolt>(0.208) <= c.v_oc
-        && c.v_oc <= ElectricPotential::new::<kilovolt>(0.600))
-        && !(ElectricCurrent::new::<kiloampere>(0.500) <= i_bf
-            && i_bf <= ElectricCurrent::new::<kiloampere>(106.000))
-    {
-        return Err(format_err!(
-            "I_bf = {} is outside LV calculation range 500 A to 106 kA.",
-            i_bf.into_format_args(kiloampere, Abbreviation)
-        ));
-    } else if (ElectricPotential::new::<kilovolt>(0.600) < c.v_oc
-        && c.v_oc <= ElectricPotential::new::<kilovolt>(15.000))
-        && !(ElectricCurrent::new::<kiloampere>(0.200) <= i_bf
-            && i_bf <= ElectricCurrent::new::<kiloampere>(65.000))
-    {
-        return Err(format_err!(
-            "I_bf = {} is outside HV calculation range 200 A to 65 kA.",
-            i_bf.into_format_args(kiloampere, Abbreviation)
-        ));
+pub fn i_arc(c: &Cubicle, i_bf: ElectricCurrent, reduced: bool) -> Result<IArc, ArcFlashError> {
+    let (min, max) = if c.hv {
+        (
+            ElectricCurrent::new::<kiloampere>(0.200),
+            ElectricCurrent::new::<kiloampere>(65.000),
+        )
+    } else {
+        (
+            ElectricCurrent::new::<kiloampere>(0.500),
+            ElectricCurrent::new::<kiloampere>(106.000),
+        )
+    };
+    if !(min <= i_bf && i_bf <= max) {
+        return Err(ArcFlashError::IbfOutOfRange { i_bf, min, max });
     }
 
     if c.hv {
-        let i_arc_600_full = i_arc_intermediate(&c, NominalVoltage::V600, i_bf);
-        let i_arc_2700_full = i_arc_intermediate(&c, NominalVoltage::V2700, i_bf);
-        let i_arc_14300_full = i_arc_intermediate(&c, NominalVoltage::V14300, i_bf);
+        let i_arc_600_full = i_arc_intermediate(c, NominalVoltage::V600, i_bf);
+        let i_arc_2700_full = i_arc_intermediate(c, NominalVoltage::V2700, i_bf);
+        let i_arc_14300_full = i_arc_intermediate(c, NominalVoltage::V14300, i_bf);
         if !reduced {
             let i_arc_600 = i_arc_600_full;
             let i_arc_2700 = i_arc_2700_full;
@@ -124,12 +161,13 @@ pub fn i_arc(c: &Cubicle, i_bf: ElectricCurrent, reduced: bool) -> Result<IArc>
                 i_arc_14300,
                 i_arc_2700,
                 i_arc_600,
-                i_arc: interpolate!(&c, i_arc_600, i_arc_2700, i_arc_14300),
+                i_arc: interpolate!(c.v_oc, i_arc_600, i_arc_2700, i_arc_14300)?,
+                warnings: i_arc_warnings(c),
             }))
         } else {
-            let i_arc_600 = i_arc_min(&c, i_arc_600_full);
-            let i_arc_2700 = i_arc_min(&c, i_arc_2700_full);
-            let i_arc_14300 = i_arc_min(&c, i_arc_14300_full);
+            let i_arc_600 = i_arc_min(c, i_arc_600_full);
+            let i_arc_2700 = i_arc_min(c, i_arc_2700_full);
+            let i_arc_14300 = i_arc_min(c, i_arc_14300_full);
 
             Ok(IArc::HighVoltage(IArcHV {
                 i_bf,
@@ -137,17 +175,18 @@ pub fn i_arc(c: &Cubicle, i_bf: ElectricCurrent, reduced: bool) -> Result<IArc>
                 i_arc_14300,
                 i_arc_2700,
                 i_arc_600,
-                i_arc: interpolate!(&c, i_arc_600, i_arc_2700, i_arc_14300),
+                i_arc: interpolate!(c.v_oc, i_arc_600, i_arc_2700, i_arc_14300)?,
+                warnings: i_arc_warnings(c),
             }))
         }
     } else {
-        let i_arc_600 = i_arc_intermediate(&c, NominalVoltage::V600, i_bf);
-        let i_arc_full = i_arc_final_lv(&c, i_arc_600, i_bf);
+        let i_arc_600 = i_arc_intermediate(c, NominalVoltage::V600, i_bf);
+        let i_arc_full = i_arc_final_lv(c, i_arc_600, i_bf);
 
         let i_arc = if !reduced {
             i_arc_full
         } else {
-            i_arc_min(&c, i_arc_full)
+            i_arc_min(c, i_arc_full)
         };
 
         Ok(IArc::LowVoltage(IArcLV {
@@ -155,6 +194,7 @@ pub fn i_arc(c: &Cubicle, i_bf: ElectricCurrent, reduced: bool) -> Result<IArc>
             reduced,
             i_arc_600,
             i_arc,
+            warnings: i_arc_warnings(c),
         }))
     }
 }
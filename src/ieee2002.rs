@@ -0,0 +1,552 @@
+use anyhow::{format_err, Result};
+use std::fmt::{Display, Formatter};
+use uom::fmt::DisplayStyle::Abbreviation;
+use uom::si::electric_current::kiloampere;
+use uom::si::electric_potential::{kilovolt, volt};
+use uom::si::f64::{ElectricCurrent, ElectricPotential, Length, RadiantExposure, Time};
+use uom::si::length::millimeter;
+use uom::si::radiant_exposure::joule_per_square_centimeter;
+use uom::si::time::{millisecond, second};
+
+use crate::common::{ElectrodeConfiguration, EquipmentClass};
+use crate::cubicle::Cubicle;
+use crate::equations::E_AFB;
+
+/// System grounding, for the IEEE 1584-2002 incident energy equation.
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Grounding {
+    Ungrounded,
+    HighResistanceGrounded,
+    Grounded,
+}
+
+impl Grounding {
+    /// K2 of IEEE 1584-2002 Equation (2).
+    fn k2(&self) -> f64 {
+        match self {
+            Grounding::Ungrounded | Grounding::HighResistanceGrounded => 0.0,
+            Grounding::Grounded => -0.113,
+        }
+    }
+}
+
+/// Types of equipment of IEEE 1584-2002 Table 4, for the distance exponent x.
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Equipment2002 {
+    OpenAir,
+    Switchgear,
+    /// MCCs and panels.
+    MccPanel,
+    Cable,
+}
+
+impl Equipment2002 {
+    /// Distance exponent x, from IEEE 1584-2002 Table 4. Table 4 has no MCCs above 1 kV, so they are taken to be
+    /// switchgear.
+    pub fn x(&self, v_oc: ElectricPotential) -> f64 {
+        let lv = v_oc <= ElectricPotential::new::<kilovolt>(1.0);
+        match self {
+            Equipment2002::OpenAir | Equipment2002::Cable => 2.0,
+            Equipment2002::Switchgear if lv => 1.473,
+            Equipment2002::MccPanel if lv => 1.641,
+            Equipment2002::Switchgear | Equipment2002::MccPanel => 0.973,
+        }
+    }
+}
+
+impl From<EquipmentClass> for Equipment2002 {
+    fn from(class: EquipmentClass) -> Self {
+        match class {
+            EquipmentClass::Switchgear15kV
+            | EquipmentClass::Switchgear5kV
+            | EquipmentClass::Switchgear5kVTall
+            | EquipmentClass::LvSwitchgear => Equipment2002::Switchgear,
+            EquipmentClass::Mcc15kV
+            | EquipmentClass::Mcc5kV
+            | EquipmentClass::LvMccShallow
+            | EquipmentClass::LvPanelboardShallow
+            | EquipmentClass::LvMcc
+            | EquipmentClass::LvPanelboard => Equipment2002::MccPanel,
+            EquipmentClass::CableJunctionBoxShallow | EquipmentClass::CableJunctionBox => {
+                Equipment2002::Cable
+            }
+        }
+    }
+}
+
+/// Inputs to the IEEE 1584-2002 model.
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub struct Inputs2002 {
+    pub v_oc: ElectricPotential,
+    /// Gap between conductors.
+    pub g: Length,
+    /// Working distance.
+    pub d: Length,
+    /// True for an arc in a box, false for an arc in open air.
+    pub enclosed: bool,
+    pub equipment: Equipment2002,
+    pub grounding: Grounding,
+}
+
+impl Inputs2002 {
+    /// The inputs for a cubicle of the 2018 model. The arc is in a box unless the electrode configuration is VOA or
+    /// HOA.
+    pub fn from_cubicle(c: &Cubicle, equipment: Equipment2002, grounding: Grounding) -> Self {
+        Self {
+            v_oc: c.v_oc,
+            g: c.g,
+            d: c.d,
+            enclosed: !matches!(
+                c.ec,
+                ElectrodeConfiguration::VOA | ElectrodeConfiguration::HOA
+            ),
+            equipment,
+            grounding,
+        }
+    }
+
+    fn lv(&self) -> bool {
+        self.v_oc < ElectricPotential::new::<kilovolt>(1.0)
+    }
+
+    /// Check the range of the model, IEEE 1584-2002 s4.2.
+    fn check(&self) -> Result<()> {
+        let v = self.v_oc.get::<kilovolt>();
+        if !(0.208..=15.0).contains(&v) {
+            return Err(format_err!(
+                "voltage {} kV is outside the range of the IEEE 1584-2002 model (0.208 kV - 15 kV)",
+                v
+            ));
+        }
+        let g = self.g.get::<millimeter>();
+        if !(13.0..=152.0).contains(&g) {
+            return Err(format_err!(
+                "gap {} mm is outside the range of the IEEE 1584-2002 model (13 mm - 152 mm)",
+                g
+            ));
+        }
+        if !(self.d.get::<millimeter>() > 0.0 && self.d.is_finite()) {
+            return Err(format_err!("working distance must be positive"));
+        }
+        Ok(())
+    }
+}
+
+/// Arcing current by IEEE 1584-2002.
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub struct IArc2002 {
+    pub i_bf: ElectricCurrent,
+    pub i_arc: ElectricCurrent,
+    /// 85 % of the arcing current, for the second clearing time calculation below 1 kV (s5.2). `None` at 1 kV and
+    /// above.
+    pub i_arc_reduced: Option<ElectricCurrent>,
+}
+
+/// Calculate the arcing current by IEEE 1584-2002 Equations (1) and (1.1).
+pub fn i_arc_2002(inputs: &Inputs2002, i_bf: ElectricCurrent) -> Result<IArc2002> {
+    inputs.check()?;
+    let ibf = i_bf.get::<kiloampere>();
+    if !(0.7..=106.0).contains(&ibf) {
+        return Err(format_err!(
+            "bolted fault current {} kA is outside the range of the IEEE 1584-2002 model (0.7 kA - 106 kA)",
+            ibf
+        ));
+    }
+
+    let lg_ibf = ibf.log10();
+    let lg_ia = if inputs.lv() {
+        let v = inputs.v_oc.get::<kilovolt>();
+        let g = inputs.g.get::<millimeter>();
+        let k = if inputs.enclosed { -0.097 } else { -0.153 };
+        k + 0.662 * lg_ibf + 0.0966 * v + 0.000526 * g + 0.5588 * v * lg_ibf - 0.00304 * g * lg_ibf
+    } else {
+        0.00402 + 0.983 * lg_ibf
+    };
+    let i_arc = ElectricCurrent::new::<kiloampere>(10.0_f64.powf(lg_ia));
+
+    Ok(IArc2002 {
+        i_bf,
+        i_arc,
+        i_arc_reduced: inputs.lv().then(|| 0.85 * i_arc),
+    })
+}
+
+/// Incident energy and AFB by IEEE 1584-2002.
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub struct EAfb2002 {
+    pub i_arc: ElectricCurrent,
+    pub t_arc: Time,
+    /// Normalized incident energy En of Equation (2), for an arc duration of 0.2 s at 610 mm.
+    pub e_n: f64,
+    /// Calculation factor Cf.
+    pub cf: f64,
+    /// Distance exponent x.
+    pub x: f64,
+    pub e: RadiantExposure,
+    pub afb: Length,
+}
+
+impl EAfb2002 {
+    /// Calculate the incident energy at distance `d`, rather than at the working distance (Equation (4)).
+    pub fn e_at_distance(&self, d: Length) -> RadiantExposure {
+        let e = 4.184
+            * self.cf
+            * self.e_n
+            * (self.t_arc.get::<second>() / 0.2)
+            * (610.0_f64.powf(self.x) / d.get::<millimeter>().powf(self.x));
+        RadiantExposure::new::<joule_per_square_centimeter>(e)
+    }
+
+    /// Calculate the distance at which the incident energy falls to `e_b` (Equation (5)).
+    pub fn distance_for_e(&self, e_b: RadiantExposure) -> Length {
+        let d = (4.184
+            * self.cf
+            * self.e_n
+            * (self.t_arc.get::<second>() / 0.2)
+            * (610.0_f64.powf(self.x) / e_b.get::<joule_per_square_centimeter>()))
+        .powf(1.0 / self.x);
+        Length::new::<millimeter>(d)
+    }
+}
+
+/// Calculate the incident energy and AFB (1.2 cal/cm²) by IEEE 1584-2002 Equations (2) to (5), for arcing current
+/// `i_arc` (either of the currents of `IArc2002`) lasting `t_arc`.
+pub fn e_afb_2002(inputs: &Inputs2002, i_arc: ElectricCurrent, t_arc: Time) -> Result<EAfb2002> {
+    inputs.check()?;
+    if !(i_arc.get::<kiloampere>() > 0.0 && t_arc.get::<second>() >= 0.0 && t_arc.is_finite()) {
+        return Err(format_err!(
+            "arcing current must be positive and arc duration must not be negative"
+        ));
+    }
+
+    let k1 = if inputs.enclosed { -0.555 } else { -0.792 };
+    let lg_en = k1
+        + inputs.grounding.k2()
+        + 1.081 * i_arc.get::<kiloampere>().log10()
+        + 0.0011 * inputs.g.get::<millimeter>();
+    let cf = if inputs.v_oc <= ElectricPotential::new::<kilovolt>(1.0) {
+        1.5
+    } else {
+        1.0
+    };
+
+    let mut result = EAfb2002 {
+        i_arc,
+        t_arc,
+        e_n: 10.0_f64.powf(lg_en),
+        cf,
+        x: inputs.equipment.x(inputs.v_oc),
+        e: RadiantExposure::new::<joule_per_square_centimeter>(0.0),
+        afb: Length::new::<millimeter>(0.0),
+    };
+    result.e = result.e_at_distance(inputs.d);
+    result.afb = result.distance_for_e(RadiantExposure::new::<joule_per_square_centimeter>(E_AFB));
+
+    Ok(result)
+}
+
+/// A range of bolted fault current over which a current-limiting fuse or LV circuit breaker equation of IEEE
+/// 1584-2002 (s5.6 and s5.7) applies.
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub struct DeviceEquationRange {
+    pub i_bf_min: ElectricCurrent,
+    pub i_bf_max: ElectricCurrent,
+    /// Coefficient C in cal/cm² per kA² (zero except for one Class L fuse).
+    pub c: f64,
+    /// Coefficient A in cal/cm² per kA.
+    pub a: f64,
+    /// Coefficient B in cal/cm².
+    pub b: f64,
+}
+
+impl DeviceEquationRange {
+    /// A range with coefficients in cal/cm², as in s5.6: `E = 4.184 × (C × I_bf² + A × I_bf + B)`.
+    fn cal(i_bf_min: f64, i_bf_max: f64, c: f64, a: f64, b: f64) -> Self {
+        Self {
+            i_bf_min: ElectricCurrent::new::<kiloampere>(i_bf_min),
+            i_bf_max: ElectricCurrent::new::<kiloampere>(i_bf_max),
+            c,
+            a,
+            b,
+        }
+    }
+
+    /// A range with a constant incident energy in J/cm², as in s5.6.
+    fn constant(i_bf_min: f64, i_bf_max: f64, e: f64) -> Self {
+        Self::cal(i_bf_min, i_bf_max, 0.0, 0.0, e / 4.184)
+    }
+
+    /// A range with coefficients in J/cm², as in Table 5: `E = A × I_bf + B`.
+    fn joule(i_bf_min: f64, i_bf_max: f64, a: f64, b: f64) -> Self {
+        Self::cal(i_bf_min, i_bf_max, 0.0, a / 4.184, b / 4.184)
+    }
+}
+
+/// Current-limiting fuses of IEEE 1584-2002 s5.6, by class and ampere rating.
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Fuse2002 {
+    /// Class L, 1601 A to 2000 A.
+    ClassL2000,
+    /// Class L, 1201 A to 1600 A.
+    ClassL1600,
+    /// Class L, 801 A to 1200 A.
+    ClassL1200,
+    /// Class L, 601 A to 800 A.
+    ClassL800,
+    /// Class RK1, 401 A to 600 A.
+    ClassRk1_600,
+    /// Class RK1, 201 A to 400 A.
+    ClassRk1_400,
+    /// Class RK1, 101 A to 200 A.
+    ClassRk1_200,
+    /// Class RK1, 100 A and less.
+    ClassRk1_100,
+}
+
+/// Low voltage circuit breakers of IEEE 1584-2002 Table 5, by type, rating and trip unit.
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Breaker2002 {
+    /// MCCB, 100 A to 400 A, thermal-magnetic or magnetic trip.
+    Mccb400Tm,
+    /// MCCB, 600 A to 1200 A, thermal-magnetic or magnetic trip.
+    Mccb1200Tm,
+    /// MCCB, 600 A to 1200 A, electronic trip with long-time and instantaneous functions.
+    Mccb1200Li,
+    /// MCCB or ICCB, 1600 A to 6000 A, thermal-magnetic or electronic (LI) trip.
+    Mccb6000,
+    /// LVPCB, 800 A to 6300 A, electronic trip with long-time and instantaneous functions.
+    LvpcbLi,
+    /// LVPCB, 800 A to 6300 A, electronic trip with long-time and short-time functions.
+    LvpcbLs,
+}
+
+/// A current-limiting fuse or LV circuit breaker with an equation of IEEE 1584-2002 (see `DeviceEquation::device`).
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Device2002 {
+    Fuse(Fuse2002),
+    Breaker(Breaker2002),
+}
+
+/// The incident energy for a particular current-limiting fuse or LV circuit breaker, in the piecewise form of IEEE
+/// 1584-2002 s5.6 and s5.7: `E = 4.184 × (C × I_bf² + A × I_bf + B)` J/cm² at a working distance of 455 mm.
+///
+/// The equations include the clearing time of the device, so no separate clearing time is needed. Outside their
+/// ranges the incident energy is to be found from the arcing current and the time-current curve of the device.
+#[derive(PartialEq, Clone, Debug)]
+pub struct DeviceEquation {
+    pub ranges: Vec<DeviceEquationRange>,
+}
+
+impl DeviceEquation {
+    /// Working distance of the equations.
+    pub fn distance() -> Length {
+        Length::new::<millimeter>(455.0)
+    }
+
+    /// The equations of s5.6 for a current-limiting fuse at 600 V.
+    pub fn fuse(fuse: Fuse2002) -> Self {
+        use DeviceEquationRange as R;
+        let ranges = match fuse {
+            Fuse2002::ClassL2000 => vec![
+                R::cal(22.6, 65.9, 0.0, -0.1284, 32.262),
+                R::cal(65.9, 106.0, 0.0, -0.5177, 57.917),
+            ],
+            Fuse2002::ClassL1600 => vec![
+                R::cal(15.7, 31.8, 0.0, -0.1863, 27.926),
+                R::cal(31.8, 44.1, 0.0, -1.5504, 71.303),
+                R::constant(44.1, 65.9, 12.3),
+                R::cal(65.9, 106.0, 0.0, -0.0631, 7.0878),
+            ],
+            Fuse2002::ClassL1200 => vec![
+                R::cal(15.7, 22.6, 0.0, -0.1928, 14.226),
+                R::cal(22.6, 44.1, 0.0143, -1.3919, 34.045),
+                R::constant(44.1, 106.0, 1.63),
+            ],
+            Fuse2002::ClassL800 => vec![
+                R::cal(15.7, 44.1, 0.0, -0.0601, 2.8992),
+                R::constant(44.1, 106.0, 1.046),
+            ],
+            Fuse2002::ClassRk1_600 => vec![
+                R::cal(8.5, 14.0, 0.0, -3.0545, 43.364),
+                R::constant(14.0, 15.7, 2.510),
+                R::cal(15.7, 22.6, 0.0, -0.0507, 1.3964),
+                R::constant(22.6, 106.0, 1.046),
+            ],
+            Fuse2002::ClassRk1_400 => vec![
+                R::cal(3.16, 5.04, 0.0, -19.053, 96.808),
+                R::cal(5.04, 22.6, 0.0, -0.0302, 0.9321),
+                R::constant(22.6, 106.0, 1.046),
+            ],
+            Fuse2002::ClassRk1_200 => vec![
+                R::cal(1.16, 1.6, 0.0, -18.409, 36.355),
+                R::cal(1.6, 3.16, 0.0, -4.2628, 13.721),
+                R::constant(3.16, 106.0, 1.046),
+            ],
+            Fuse2002::ClassRk1_100 => vec![
+                R::cal(0.65, 1.16, 0.0, -11.176, 13.565),
+                R::cal(1.16, 1.4, 0.0, -1.4583, 2.2917),
+                R::constant(1.4, 106.0, 1.046),
+            ],
+        };
+        Self { ranges }
+    }
+
+    /// The equation for `device` at system voltage `v_oc`, as for `fuse` or `breaker`.
+    pub fn device(device: Device2002, v_oc: ElectricPotential) -> Result<Self> {
+        match device {
+            Device2002::Fuse(fuse) => Ok(Self::fuse(fuse)),
+            Device2002::Breaker(breaker) => Self::breaker(breaker, v_oc),
+        }
+    }
+
+    /// The equation of Table 5 for an LV circuit breaker at system voltage `v_oc`, 480 V and lower or 575 V to
+    /// 600 V, for bolted fault currents from 0.7 kA to 106 kA. The arcing current must be above the instantaneous
+    /// (or, for `LvpcbLs`, short-time) pickup of the breaker.
+    pub fn breaker(breaker: Breaker2002, v_oc: ElectricPotential) -> Result<Self> {
+        let v = v_oc.get::<volt>();
+        let (a, b) = if v > 0.0 && v <= 480.0 {
+            match breaker {
+                Breaker2002::Mccb400Tm => (0.189, 0.548),
+                Breaker2002::Mccb1200Tm => (0.223, 1.590),
+                Breaker2002::Mccb1200Li => (0.377, 1.360),
+                Breaker2002::Mccb6000 => (0.448, 3.000),
+                Breaker2002::LvpcbLi => (0.636, 3.670),
+                Breaker2002::LvpcbLs => (4.560, 27.230),
+            }
+        } else if (575.0..=600.0).contains(&v) {
+            match breaker {
+                Breaker2002::Mccb400Tm => (0.271, 0.180),
+                Breaker2002::Mccb1200Tm => (0.335, 0.380),
+                Breaker2002::Mccb1200Li => (0.468, 4.600),
+                Breaker2002::Mccb6000 => (0.686, 0.165),
+                Breaker2002::LvpcbLi => (0.958, 0.292),
+                Breaker2002::LvpcbLs => (6.860, 2.170),
+            }
+        } else {
+            return Err(format_err!(
+                "IEEE 1584-2002 Table 5 has no equations at {} V (480 V and lower, or 575 V - 600 V)",
+                v
+            ));
+        };
+        Ok(Self {
+            ranges: vec![DeviceEquationRange::joule(0.7, 106.0, a, b)],
+        })
+    }
+
+    /// The incident energy at 455 mm for bolted fault current `i_bf`, or `None` if `i_bf` is outside the ranges of
+    /// the equation.
+    pub fn e(&self, i_bf: ElectricCurrent) -> Option<RadiantExposure> {
+        let range = self
+            .ranges
+            .iter()
+            .find(|range| range.i_bf_min <= i_bf && i_bf <= range.i_bf_max)?;
+        let i = i_bf.get::<kiloampere>();
+        let e = 4.184 * (range.c * i * i + range.a * i + range.b);
+        Some(RadiantExposure::new::<joule_per_square_centimeter>(
+            e.max(0.0),
+        ))
+    }
+
+    /// The incident energy at the working distance of `inputs`, scaled from 455 mm with the distance exponent, or
+    /// `None` if `i_bf` is outside the ranges of the equation. The inputs are checked as for `e_afb_2002`.
+    pub fn e_at_distance(
+        &self,
+        inputs: &Inputs2002,
+        i_bf: ElectricCurrent,
+    ) -> Result<Option<RadiantExposure>> {
+        inputs.check()?;
+        let x = inputs.equipment.x(inputs.v_oc);
+        let ratio = (Self::distance() / inputs.d).value.powf(x);
+        Ok(self.e(i_bf).map(|e| e * ratio))
+    }
+
+    /// Calculate the incident energy and AFB (1.2 cal/cm²) for bolted fault current `i_bf` at the working distance of
+    /// `inputs`, or `None` if `i_bf` is outside the ranges of the equation. `i_arc` and `t_arc` are those of the
+    /// arcing current case, and are kept for reference only: the equation includes the clearing time of the device.
+    pub fn e_afb(
+        &self,
+        inputs: &Inputs2002,
+        i_bf: ElectricCurrent,
+        i_arc: ElectricCurrent,
+        t_arc: Time,
+    ) -> Result<Option<DeviceEAfb2002>> {
+        inputs.check()?;
+        let e_455 = match self.e(i_bf) {
+            Some(e) => e,
+            None => return Ok(None),
+        };
+
+        let mut result = DeviceEAfb2002 {
+            i_arc,
+            t_arc,
+            e_455,
+            x: inputs.equipment.x(inputs.v_oc),
+            e: RadiantExposure::new::<joule_per_square_centimeter>(0.0),
+            afb: Length::new::<millimeter>(0.0),
+        };
+        result.e = result.e_at_distance(inputs.d);
+        result.afb =
+            result.distance_for_e(RadiantExposure::new::<joule_per_square_centimeter>(E_AFB));
+
+        Ok(Some(result))
+    }
+}
+
+/// Incident energy and AFB by the equation of a current-limiting fuse or LV circuit breaker of IEEE 1584-2002 (see
+/// `DeviceEquation`).
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub struct DeviceEAfb2002 {
+    pub i_arc: ElectricCurrent,
+    /// Arc duration from the time-current curve of the device, which the equation does not use.
+    pub t_arc: Time,
+    /// Incident energy at 455 mm, from the equation.
+    pub e_455: RadiantExposure,
+    /// Distance exponent x.
+    pub x: f64,
+    pub e: RadiantExposure,
+    pub afb: Length,
+}
+
+impl DeviceEAfb2002 {
+    /// Calculate the incident energy at distance `d`, rather than at the working distance.
+    pub fn e_at_distance(&self, d: Length) -> RadiantExposure {
+        self.e_455 * (DeviceEquation::distance() / d).value.powf(self.x)
+    }
+
+    /// Calculate the distance at which the incident energy falls to `e_b`.
+    pub fn distance_for_e(&self, e_b: RadiantExposure) -> Length {
+        DeviceEquation::distance() * (self.e_455 / e_b).value.powf(1.0 / self.x)
+    }
+}
+
+impl Display for DeviceEAfb2002 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IEEE 1584-2002 (device equation): I_arc = {}, E = {}, AFB = {}",
+            self.i_arc.into_format_args(kiloampere, Abbreviation),
+            self.e
+                .into_format_args(joule_per_square_centimeter, Abbreviation),
+            self.afb.into_format_args(millimeter, Abbreviation)
+        )
+    }
+}
+
+impl Display for EAfb2002 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IEEE 1584-2002: I_arc = {}, T_arc = {}, E = {}, AFB = {}",
+            self.i_arc.into_format_args(kiloampere, Abbreviation),
+            self.t_arc.into_format_args(millisecond, Abbreviation),
+            self.e
+                .into_format_args(joule_per_square_centimeter, Abbreviation),
+            self.afb.into_format_args(millimeter, Abbreviation)
+        )
+    }
+}
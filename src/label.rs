@@ -0,0 +1,241 @@
+use anyhow::Result;
+use std::fmt::Write;
+use uom::si::electric_potential::volt;
+use uom::si::f64::{ElectricPotential, Length, RadiantExposure};
+use uom::si::length::{inch, millimeter};
+use uom::si::radiant_exposure::joule_per_square_centimeter;
+
+use crate::approach::{approach_boundaries, ApproachDistance};
+use crate::common::units::calorie_per_square_centimeter as cal_per_sq_cm;
+use crate::e_afb::EAfb;
+use crate::error::ArcFlashError;
+use crate::method::ArcFlashResult;
+use crate::ppe::PpeTable;
+
+/// Unit for distances printed on a label.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum LengthUnit {
+    Millimeter,
+    Inch,
+}
+
+/// Unit for incident energy printed on a label.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum EnergyUnit {
+    JoulePerSquareCentimeter,
+    CaloriePerSquareCentimeter,
+}
+
+/// Label signal word, as per ANSI Z535.4.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum SignalWord {
+    Warning,
+    Danger,
+}
+
+/// Size and units of an arc flash label.
+pub struct LabelTemplate {
+    pub width: Length,
+    pub height: Length,
+    pub length_unit: LengthUnit,
+    pub energy_unit: EnergyUnit,
+}
+
+impl Default for LabelTemplate {
+    /// A 6 in x 4 in label, with distances in mm and energy in cal/cm².
+    fn default() -> Self {
+        Self {
+            width: Length::new::<inch>(6.0),
+            height: Length::new::<inch>(4.0),
+            length_unit: LengthUnit::Millimeter,
+            energy_unit: EnergyUnit::CaloriePerSquareCentimeter,
+        }
+    }
+}
+
+/// Equipment details printed on a label, in addition to the calculation results.
+pub struct LabelInfo<'a> {
+    /// Equipment name or designation.
+    pub equipment: &'a str,
+    /// Nominal system voltage, as it should appear on the label. The shock approach boundaries are for this voltage.
+    pub nominal_voltage: ElectricPotential,
+    /// Working distance at which the incident energy is given.
+    pub working_distance: Length,
+    /// PPE to select from. The PPE and minimum arc rating are selected for the incident energy printed on the
+    /// label, i.e. at `working_distance`.
+    pub ppe: &'a PpeTable,
+}
+
+/// The signal word for a label: "DANGER" where the incident energy exceeds 40 cal/cm² or the nominal voltage
+/// exceeds 600 V, and "WARNING" otherwise.
+pub fn signal_word(e: RadiantExposure, nominal_voltage: ElectricPotential) -> SignalWord {
+    if e > RadiantExposure::new::<cal_per_sq_cm>(40.0)
+        || nominal_voltage > ElectricPotential::new::<volt>(600.0)
+    {
+        SignalWord::Danger
+    } else {
+        SignalWord::Warning
+    }
+}
+
+impl LabelTemplate {
+    fn length(&self, d: Length) -> String {
+        match self.length_unit {
+            LengthUnit::Millimeter => format!("{:.0} mm", d.get::<millimeter>()),
+            LengthUnit::Inch => format!("{:.1} in", d.get::<inch>()),
+        }
+    }
+
+    fn energy(&self, e: RadiantExposure) -> String {
+        match self.energy_unit {
+            EnergyUnit::JoulePerSquareCentimeter => {
+                format!("{:.1} J/cm²", e.get::<joule_per_square_centimeter>())
+            }
+            EnergyUnit::CaloriePerSquareCentimeter => {
+                format!("{:.1} cal/cm²", e.get::<cal_per_sq_cm>())
+            }
+        }
+    }
+
+    fn approach(&self, d: Option<ApproachDistance>) -> String {
+        match d {
+            Some(ApproachDistance::Distance(d)) => self.length(d),
+            Some(ApproachDistance::AvoidContact) => "Avoid contact".to_string(),
+            Some(ApproachDistance::NotSpecified) | None => "Not specified".to_string(),
+        }
+    }
+
+    /// Render an ANSI Z535 style arc flash warning label as SVG.
+    ///
+    /// `e_afb` is the result of the governing case. The working distance of `info` must be positive and finite.
+    pub fn render_svg(&self, e_afb: &EAfb, info: &LabelInfo) -> Result<String, ArcFlashError> {
+        let e = if info.working_distance == e_afb.basis().d {
+            e_afb.e()
+        } else {
+            e_afb.e_at_distance(info.working_distance)?
+        };
+        Ok(self.render(e, e_afb.afb(), info))
+    }
+
+    /// Render a label as `render_svg`, for the governing case of any calculation method (see `ArcFlashMethod`).
+    pub fn render_result_svg(
+        &self,
+        result: &dyn ArcFlashResult,
+        info: &LabelInfo,
+    ) -> Result<String> {
+        Ok(self.render(
+            result.e_at_distance(info.working_distance)?,
+            result.afb(),
+            info,
+        ))
+    }
+
+    /// Render a label for incident energy `e` at the working distance of `info`.
+    fn render(&self, e: RadiantExposure, afb: Length, info: &LabelInfo) -> String {
+        let w = self.width.get::<millimeter>();
+        let h = self.height.get::<millimeter>();
+
+        let (signal, fill, text_fill) = match signal_word(e, info.nominal_voltage) {
+            SignalWord::Danger => ("DANGER", "#C8102E", "#FFFFFF"),
+            SignalWord::Warning => ("WARNING", "#FF7900", "#000000"),
+        };
+
+        let header = h * 0.2;
+        let font = h * 0.055;
+        let margin = w * 0.04;
+
+        // The PPE is selected for the energy on the label, so that the two always agree.
+        let selection = info.ppe.select(e);
+        let approach = approach_boundaries(info.nominal_voltage).ok();
+
+        let ppe = match &selection.ppe {
+            Some(ppe) => format!("{} ({})", ppe.name, self.energy(ppe.arc_rating)),
+            None => "No PPE category applies".to_string(),
+        };
+
+        let lines = [
+            (true, "Arc Flash and Shock Hazard".to_string()),
+            (false, "Appropriate PPE Required".to_string()),
+            (false, format!("Arc flash boundary: {}", self.length(afb))),
+            (
+                false,
+                format!(
+                    "Incident energy: {} at {}",
+                    self.energy(e),
+                    self.length(info.working_distance)
+                ),
+            ),
+            (
+                false,
+                format!(
+                    "Minimum arc rating: {}",
+                    self.energy(selection.min_arc_rating())
+                ),
+            ),
+            (false, format!("PPE: {}", ppe)),
+            (
+                false,
+                format!("Shock hazard: {:.0} V", info.nominal_voltage.get::<volt>()),
+            ),
+            (
+                false,
+                format!(
+                    "Limited approach: {}",
+                    self.approach(approach.map(|a| a.limited_fixed))
+                ),
+            ),
+            (
+                false,
+                format!(
+                    "Restricted approach: {}",
+                    self.approach(approach.map(|a| a.restricted))
+                ),
+            ),
+            (true, format!("Equipment: {}", info.equipment)),
+        ];
+
+        let mut svg = String::new();
+        let _ = write!(
+            svg,
+            r##"<svg xmlns="http://www.w3.org/2000/svg" width="{w}mm" height="{h}mm" viewBox="0 0 {w} {h}">
+<rect x="0" y="0" width="{w}" height="{h}" fill="#FFFFFF" stroke="#000000" stroke-width="0.5"/>
+<rect x="0" y="0" width="{w}" height="{header}" fill="{fill}"/>
+<path d="M {tx} {ty0} L {tx1} {ty1} L {tx2} {ty1} Z" fill="{text_fill}"/>
+<text x="{tx}" y="{tmark}" font-family="Arial, Helvetica, sans-serif" font-size="{tfont}" font-weight="bold" text-anchor="middle" fill="{fill}">!</text>
+<text x="{sx}" y="{sy}" font-family="Arial, Helvetica, sans-serif" font-size="{sfont}" font-weight="bold" fill="{text_fill}">{signal}</text>
+"##,
+            tx = margin + header * 0.4,
+            ty0 = header * 0.15,
+            tx1 = margin + header * 0.8,
+            tx2 = margin,
+            ty1 = header * 0.85,
+            tmark = header * 0.78,
+            tfont = header * 0.5,
+            sx = margin + header,
+            sy = header * 0.72,
+            sfont = header * 0.6,
+        );
+
+        let spacing = (h - header) / (lines.len() as f64 + 1.0);
+        for (i, (bold, line)) in lines.iter().enumerate() {
+            let _ = writeln!(
+                svg,
+                r##"<text x="{x}" y="{y}" font-family="Arial, Helvetica, sans-serif" font-size="{font}"{weight} fill="#000000">{text}</text>"##,
+                x = margin,
+                y = header + spacing * (i as f64 + 1.0),
+                weight = if *bold { r#" font-weight="bold""# } else { "" },
+                text = escape(line),
+            );
+        }
+        svg.push_str("</svg>\n");
+
+        svg
+    }
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
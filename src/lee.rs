@@ -0,0 +1,132 @@
+use anyhow::{format_err, Result};
+use std::fmt::{Display, Formatter};
+use uom::fmt::DisplayStyle::Abbreviation;
+use uom::si::electric_current::kiloampere;
+use uom::si::electric_potential::kilovolt;
+use uom::si::f64::{ElectricCurrent, ElectricPotential, Length, RadiantExposure, Time};
+use uom::si::length::millimeter;
+use uom::si::radiant_exposure::joule_per_square_centimeter;
+use uom::si::time::{millisecond, second};
+
+use crate::equations::E_AFB;
+
+/// Incident energy and AFB by the theoretical method of Ralph Lee.
+///
+/// This is **not** an IEEE 1584-2018 result. It is for systems outside the range of the IEEE 1584-2018 model, in
+/// particular above 15 kV, where NFPA 70E Annex D.2 directs its use. It assumes the maximum arc power (half the bolted
+/// fault MVA) radiated in open air, and is generally conservative:
+///
+/// ```text
+/// E = 2.142 × 10⁶ × V × I_bf × t / D²
+/// ```
+///
+/// with E in J/cm², V in kV, I_bf in kA, t in s and D in mm.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "method", rename = "lee"))]
+#[derive(Clone, Debug)]
+pub struct LeeEAfb {
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "v_oc_kv", with = "crate::serde_units::kilovolt")
+    )]
+    pub v_oc: ElectricPotential,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "i_bf_ka", with = "crate::serde_units::kiloampere")
+    )]
+    pub i_bf: ElectricCurrent,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "t_arc_ms", with = "crate::serde_units::millisecond")
+    )]
+    pub t_arc: Time,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "d_mm", with = "crate::serde_units::millimeter")
+    )]
+    pub d: Length,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "afb_mm", with = "crate::serde_units::millimeter")
+    )]
+    pub afb: Length,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "e_j_per_cm2",
+            with = "crate::serde_units::joule_per_square_centimeter"
+        )
+    )]
+    pub e: RadiantExposure,
+}
+
+impl LeeEAfb {
+    /// `2.142 × 10⁶ × V × I_bf × t`, in J/cm² × mm².
+    fn energy(&self) -> f64 {
+        2.142e6
+            * self.v_oc.get::<kilovolt>()
+            * self.i_bf.get::<kiloampere>()
+            * self.t_arc.get::<second>()
+    }
+
+    /// Calculate the incident energy at distance `d`, rather than at the working distance.
+    pub fn e_at_distance(&self, d: Length) -> RadiantExposure {
+        RadiantExposure::new::<joule_per_square_centimeter>(
+            self.energy() / d.get::<millimeter>().powi(2),
+        )
+    }
+
+    /// Calculate the distance at which the incident energy falls to `e_b`.
+    pub fn distance_for_e(&self, e_b: RadiantExposure) -> Length {
+        Length::new::<millimeter>((self.energy() / e_b.get::<joule_per_square_centimeter>()).sqrt())
+    }
+}
+
+/// Calculate the incident energy at working distance `d` and the AFB (1.2 cal/cm²) by the Lee method, for a system
+/// with voltage `v_oc` and bolted fault current `i_bf`, cleared after `t_arc`.
+pub fn lee_e_afb(
+    v_oc: ElectricPotential,
+    i_bf: ElectricCurrent,
+    t_arc: Time,
+    d: Length,
+) -> Result<LeeEAfb> {
+    let positive = |x: f64| x > 0.0 && x.is_finite();
+    if !(positive(v_oc.get::<kilovolt>()) && positive(i_bf.get::<kiloampere>())) {
+        return Err(format_err!(
+            "voltage and bolted fault current must be positive"
+        ));
+    }
+    if !(t_arc.get::<second>() >= 0.0 && t_arc.is_finite() && positive(d.get::<millimeter>())) {
+        return Err(format_err!(
+            "arc duration must not be negative and working distance must be positive"
+        ));
+    }
+
+    let mut result = LeeEAfb {
+        v_oc,
+        i_bf,
+        t_arc,
+        d,
+        afb: Length::new::<millimeter>(0.0),
+        e: RadiantExposure::new::<joule_per_square_centimeter>(0.0),
+    };
+    result.e = result.e_at_distance(d);
+    result.afb = result.distance_for_e(RadiantExposure::new::<joule_per_square_centimeter>(E_AFB));
+
+    Ok(result)
+}
+
+impl Display for LeeEAfb {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Lee method (not IEEE 1584-2018): V_oc = {}, I_bf = {}, T_arc = {}, E = {}, AFB = {}",
+            self.v_oc.into_format_args(kilovolt, Abbreviation),
+            self.i_bf.into_format_args(kiloampere, Abbreviation),
+            self.t_arc.into_format_args(millisecond, Abbreviation),
+            self.e
+                .into_format_args(joule_per_square_centimeter, Abbreviation),
+            self.afb.into_format_args(millimeter, Abbreviation)
+        )
+    }
+}
@@ -1,18 +1,69 @@
 //! AC arc flash calculations according to IEEE 1584 (for 3-phase AC systems, 208 V - 15,000 V).
 
+#[macro_use]
+extern crate uom;
+
+mod approach;
+mod batch;
+mod builder;
+mod coefficients;
 mod common;
 mod cubicle;
+mod dc;
 mod e_afb;
+mod edition;
+mod electrode;
 pub(crate) mod equations;
+mod error;
+mod generator;
 mod i_arc;
+mod ieee2002;
+mod label;
+mod lee;
+mod method;
+mod motor;
 mod multistep;
+mod network;
+mod ppe;
+mod profile;
+mod protection;
+pub(crate) mod quantity;
+mod scenario;
+#[cfg(feature = "serde")]
+mod serde_units;
+mod short_circuit;
 mod tables;
+mod tcc;
+mod warning;
 
 #[cfg(test)]
 mod tests;
 
+pub use approach::*;
+pub use batch::*;
+pub use builder::*;
+pub use coefficients::*;
 pub use common::*;
 pub use cubicle::*;
+pub use dc::*;
 pub use e_afb::*;
+pub use edition::*;
+pub use electrode::*;
+pub use error::*;
+pub use generator::*;
 pub use i_arc::*;
+pub use ieee2002::*;
+pub use label::*;
+pub use lee::*;
+pub use method::*;
+pub use motor::*;
 pub use multistep::*;
+pub use network::*;
+pub use ppe::*;
+pub use profile::*;
+pub use protection::*;
+pub use scenario::*;
+pub use short_circuit::*;
+pub use tables::TypicalParams;
+pub use tcc::*;
+pub use warning::*;
@@ -0,0 +1,191 @@
+//! Command line arc flash calculations according to IEEE 1584-2018.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Read;
+use std::process::ExitCode;
+
+use arcflash::{e_afb, i_arc, read_batch, run_batch, write_results, BatchRow};
+
+const USAGE: &str = "\
+Arc flash calculations according to IEEE 1584-2018.
+
+Usage:
+    arcflash calc --voltage <V> --ec <EC> --gap <G> --distance <D> --box <HxWxD> --ibf <I_bf> --t <T> [--t-reduced <T>] [--frequency <F>]
+    arcflash batch <file.csv | ->
+    arcflash help
+
+Options:
+    --voltage     nominal voltage V_oc, e.g. 4.16kV or 480V
+    --ec          electrode configuration: VCB, VCBB, HCB, VOA, or HOA
+    --gap         busbar gap G, e.g. 104mm
+    --distance    working distance D, e.g. 914.4mm
+    --box         enclosure height x width x depth, e.g. 1143x762x508mm
+    --ibf         bolted fault current I_bf, e.g. 15kA
+    --t           clearing time for the full arcing current, e.g. 197ms
+    --t-reduced   clearing time for the reduced arcing current (default: --t)
+    --frequency   system frequency, e.g. 60Hz (checked if given)
+
+Values given as plain numbers are taken to be in kV, mm, kA, ms and Hz.
+
+Batch input is CSV with a header row. Columns are named as the options above, without the
+leading dashes, plus an optional \"name\" column. Results are written to standard output as
+CSV, one row per input row; rows that failed have an \"error\" column and the other rows are
+still calculated.
+
+Exit codes:
+    0  success
+    1  input could not be read
+    2  invalid command line or input values
+    3  cubicle parameters outside the range of the model
+    4  bolted fault current outside the range of the model
+    5  one or more batch rows failed";
+
+enum CliError {
+    Usage(anyhow::Error),
+    Cubicle(anyhow::Error),
+    IArc(anyhow::Error),
+}
+
+impl CliError {
+    fn exit_code(&self) -> u8 {
+        match self {
+            CliError::Usage(_) => 2,
+            CliError::Cubicle(_) => 3,
+            CliError::IArc(_) => 4,
+        }
+    }
+
+    fn error(&self) -> &anyhow::Error {
+        match self {
+            CliError::Usage(err) | CliError::Cubicle(err) | CliError::IArc(err) => err,
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let result = match args.first().map(String::as_str) {
+        Some("calc") => parse_options(&args[1..]).and_then(|options| {
+            let output = calc(&options)?;
+            println!("{}", output);
+            Ok(())
+        }),
+        Some("batch") => return batch(&args[1..]),
+        Some("help") | Some("--help") | Some("-h") => {
+            println!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        _ => {
+            eprintln!("{}", USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err.error());
+            ExitCode::from(err.exit_code())
+        }
+    }
+}
+
+/// Parses `--key value` and `--key=value` options.
+fn parse_options(args: &[String]) -> Result<HashMap<String, String>, CliError> {
+    let mut options = HashMap::new();
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        let key = arg
+            .strip_prefix("--")
+            .ok_or_else(|| CliError::Usage(anyhow::format_err!("unexpected argument {:?}", arg)))?;
+
+        let (key, value) = match key.split_once('=') {
+            Some((key, value)) => (key.to_string(), value.to_string()),
+            None => {
+                let value = args.next().ok_or_else(|| {
+                    CliError::Usage(anyhow::format_err!("missing value for --{}", key))
+                })?;
+                (key.to_string(), value.clone())
+            }
+        };
+        options.insert(key, value);
+    }
+
+    Ok(options)
+}
+
+/// Runs a calculation for the given inputs, returning the output text.
+fn calc(options: &HashMap<String, String>) -> Result<String, CliError> {
+    // The options are read as the columns of a batch row, so plain numbers take the same units.
+    let row = BatchRow::from_fields("calc".to_string(), options).map_err(CliError::Usage)?;
+    let cubicle = row.cubicle().map_err(|err| CliError::Cubicle(err.into()))?;
+    let (i_bf, t_arc_max, t_arc_min) = (row.i_bf, row.t_full, row.t_reduced);
+
+    let i_arc_max = i_arc(&cubicle, i_bf, false).map_err(|err| CliError::IArc(err.into()))?;
+    let i_arc_min = i_arc(&cubicle, i_bf, true).map_err(|err| CliError::IArc(err.into()))?;
+
+    let e_afb_max = e_afb(&cubicle, i_arc_max.clone(), t_arc_max);
+    let e_afb_min = e_afb(&cubicle, i_arc_min.clone(), t_arc_min);
+
+    Ok(format!(
+        "{}\n\n{}\n{}\n{}\n{}\n{}",
+        cubicle,
+        i_arc_max,
+        e_afb_max,
+        i_arc_min,
+        e_afb_min,
+        if e_afb_max.e() > e_afb_min.e() {
+            "The maximum arcing current case was highest energy."
+        } else {
+            "The minimum arcing current case was highest energy."
+        }
+    ))
+}
+
+fn batch(args: &[String]) -> ExitCode {
+    let input: Box<dyn Read> = match args {
+        [path] if path == "-" => Box::new(std::io::stdin()),
+        [path] => match File::open(path) {
+            Ok(file) => Box::new(file),
+            Err(err) => {
+                eprintln!("error: {}: {}", path, err);
+                return ExitCode::from(1);
+            }
+        },
+        _ => {
+            eprintln!("{}", USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    let rows = match read_batch(input) {
+        Ok(rows) => rows,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return ExitCode::from(1);
+        }
+    };
+    let results = run_batch(rows);
+
+    if let Err(err) = write_results(std::io::stdout(), &results) {
+        eprintln!("error: {}", err);
+        return ExitCode::from(1);
+    }
+
+    let mut failed = false;
+    for result in &results {
+        if let Err(err) = &result.result {
+            eprintln!("error: {}: {}", result.name, err);
+            failed = true;
+        }
+    }
+
+    if failed {
+        ExitCode::from(5)
+    } else {
+        ExitCode::SUCCESS
+    }
+}
@@ -0,0 +1,434 @@
+use anyhow::{format_err, Result};
+use uom::si::f64::{ElectricCurrent, ElectricPotential, Length, RadiantExposure, Time};
+
+use crate::cubicle::Cubicle;
+use crate::dc::{dc_arc_current, dc_arc_flash, DcArcFlash, DcArcModel, DcEnclosure, DcSource};
+use crate::e_afb::{check_distance, check_energy, e_afb, EAfb};
+use crate::i_arc::{i_arc, IArc};
+use crate::ieee2002::{
+    e_afb_2002, i_arc_2002, DeviceEAfb2002, DeviceEquation, EAfb2002, Inputs2002,
+};
+use crate::lee::{lee_e_afb, LeeEAfb};
+use crate::protection::arc_duration;
+use crate::scenario::{ArcingCurrentCase, ClearingTimeSource};
+use crate::warning::Warning;
+
+/// The result of an arc flash calculation for one arcing current case, by any method.
+pub trait ArcFlashResult {
+    fn i_arc(&self) -> ElectricCurrent;
+    fn t_arc(&self) -> Time;
+    /// Incident energy at the working distance.
+    fn e(&self) -> RadiantExposure;
+    /// Arc flash boundary (1.2 cal/cm²).
+    fn afb(&self) -> Length;
+    /// Incident energy at distance `d`, which must be positive and finite.
+    fn e_at_distance(&self, d: Length) -> Result<RadiantExposure>;
+    /// Distance at which the incident energy falls to `e_b`, which must be positive and finite.
+    fn distance_for_e(&self, e_b: RadiantExposure) -> Result<Length>;
+    /// Warnings about the applicability of the result.
+    fn diagnostics(&self) -> Vec<Warning> {
+        Vec::new()
+    }
+}
+
+/// A method of calculating arc flash incident energy, e.g. an edition of IEEE 1584.
+///
+/// A method has one or more arcing current cases (e.g. full and reduced). The clearing time of each case is found
+/// from its arcing current, and the case with the highest incident energy governs; see `evaluate_method`.
+pub trait ArcFlashMethod {
+    /// Name of the method, for reports and labels.
+    fn name(&self) -> String;
+
+    /// The arcing current of each case, for bolted fault current `i_bf`.
+    fn arcing_currents(
+        &self,
+        i_bf: ElectricCurrent,
+    ) -> Result<Vec<(ArcingCurrentCase, ElectricCurrent)>>;
+
+    /// Calculate the incident energy and AFB of `case` for bolted fault current `i_bf`, with arc duration `t_arc`.
+    /// The result does not borrow from the method, so it can be kept after the method is dropped.
+    fn calculate(
+        &self,
+        i_bf: ElectricCurrent,
+        case: ArcingCurrentCase,
+        t_arc: Time,
+    ) -> Result<Box<dyn ArcFlashResult>>;
+}
+
+/// IEEE 1584-2018, for a cubicle.
+pub struct Ieee2018<'a> {
+    pub cubicle: &'a Cubicle,
+}
+
+/// Result of `Ieee2018`.
+pub struct Ieee2018Result {
+    pub i_arc: IArc,
+    pub e_afb: EAfb,
+}
+
+impl ArcFlashResult for Ieee2018Result {
+    fn i_arc(&self) -> ElectricCurrent {
+        self.i_arc.i_arc()
+    }
+
+    fn t_arc(&self) -> Time {
+        self.e_afb.t_arc()
+    }
+
+    fn e(&self) -> RadiantExposure {
+        self.e_afb.e()
+    }
+
+    fn afb(&self) -> Length {
+        self.e_afb.afb()
+    }
+
+    fn e_at_distance(&self, d: Length) -> Result<RadiantExposure> {
+        Ok(self.e_afb.e_at_distance(d)?)
+    }
+
+    fn distance_for_e(&self, e_b: RadiantExposure) -> Result<Length> {
+        Ok(self.e_afb.distance_for_e(e_b)?)
+    }
+
+    fn diagnostics(&self) -> Vec<Warning> {
+        self.e_afb.warnings().to_vec()
+    }
+}
+
+impl ArcFlashMethod for Ieee2018<'_> {
+    fn name(&self) -> String {
+        if self.cubicle.coefficients().is_ieee_1584_2018() {
+            "IEEE 1584-2018".to_string()
+        } else {
+            format!("IEEE 1584-2018 ({})", self.cubicle.coefficients().name)
+        }
+    }
+
+    fn arcing_currents(
+        &self,
+        i_bf: ElectricCurrent,
+    ) -> Result<Vec<(ArcingCurrentCase, ElectricCurrent)>> {
+        Ok(vec![
+            (
+                ArcingCurrentCase::Full,
+                i_arc(self.cubicle, i_bf, false)?.i_arc(),
+            ),
+            (
+                ArcingCurrentCase::Reduced,
+                i_arc(self.cubicle, i_bf, true)?.i_arc(),
+            ),
+        ])
+    }
+
+    fn calculate(
+        &self,
+        i_bf: ElectricCurrent,
+        case: ArcingCurrentCase,
+        t_arc: Time,
+    ) -> Result<Box<dyn ArcFlashResult>> {
+        let i_arc = i_arc(self.cubicle, i_bf, case == ArcingCurrentCase::Reduced)?;
+        let e_afb = e_afb(self.cubicle, i_arc.clone(), t_arc);
+        Ok(Box::new(Ieee2018Result { i_arc, e_afb }))
+    }
+}
+
+/// IEEE 1584-2002. Below 1 kV the reduced case is 85 % of the arcing current.
+///
+/// With a `device_equation`, the incident energy is by the equation of the fuse or circuit breaker where the bolted
+/// fault current is within its ranges (s5.6 and s5.7), and otherwise from the arcing current and the clearing time
+/// of the time-current curve.
+pub struct Ieee2002 {
+    pub inputs: Inputs2002,
+    pub device_equation: Option<DeviceEquation>,
+}
+
+impl ArcFlashResult for EAfb2002 {
+    fn i_arc(&self) -> ElectricCurrent {
+        self.i_arc
+    }
+
+    fn t_arc(&self) -> Time {
+        self.t_arc
+    }
+
+    fn e(&self) -> RadiantExposure {
+        self.e
+    }
+
+    fn afb(&self) -> Length {
+        self.afb
+    }
+
+    fn e_at_distance(&self, d: Length) -> Result<RadiantExposure> {
+        check_distance(d)?;
+        Ok(EAfb2002::e_at_distance(self, d))
+    }
+
+    fn distance_for_e(&self, e_b: RadiantExposure) -> Result<Length> {
+        check_energy(e_b)?;
+        Ok(EAfb2002::distance_for_e(self, e_b))
+    }
+}
+
+impl ArcFlashResult for DeviceEAfb2002 {
+    fn i_arc(&self) -> ElectricCurrent {
+        self.i_arc
+    }
+
+    fn t_arc(&self) -> Time {
+        self.t_arc
+    }
+
+    fn e(&self) -> RadiantExposure {
+        self.e
+    }
+
+    fn afb(&self) -> Length {
+        self.afb
+    }
+
+    fn e_at_distance(&self, d: Length) -> Result<RadiantExposure> {
+        check_distance(d)?;
+        Ok(DeviceEAfb2002::e_at_distance(self, d))
+    }
+
+    fn distance_for_e(&self, e_b: RadiantExposure) -> Result<Length> {
+        check_energy(e_b)?;
+        Ok(DeviceEAfb2002::distance_for_e(self, e_b))
+    }
+}
+
+impl ArcFlashMethod for Ieee2002 {
+    fn name(&self) -> String {
+        "IEEE 1584-2002".to_string()
+    }
+
+    fn arcing_currents(
+        &self,
+        i_bf: ElectricCurrent,
+    ) -> Result<Vec<(ArcingCurrentCase, ElectricCurrent)>> {
+        let i_arc = i_arc_2002(&self.inputs, i_bf)?;
+        Ok(std::iter::once((ArcingCurrentCase::Full, i_arc.i_arc))
+            .chain(i_arc.i_arc_reduced.map(|i| (ArcingCurrentCase::Reduced, i)))
+            .collect())
+    }
+
+    fn calculate(
+        &self,
+        i_bf: ElectricCurrent,
+        case: ArcingCurrentCase,
+        t_arc: Time,
+    ) -> Result<Box<dyn ArcFlashResult>> {
+        let i_arc = i_arc_2002(&self.inputs, i_bf)?;
+        let i = match case {
+            ArcingCurrentCase::Full => i_arc.i_arc,
+            ArcingCurrentCase::Reduced => i_arc.i_arc_reduced.ok_or_else(|| {
+                format_err!("IEEE 1584-2002 has no reduced case at 1 kV and above")
+            })?,
+        };
+        if let Some(equation) = &self.device_equation {
+            if let Some(result) = equation.e_afb(&self.inputs, i_bf, i, t_arc)? {
+                return Ok(Box::new(result));
+            }
+        }
+        Ok(Box::new(e_afb_2002(&self.inputs, i, t_arc)?))
+    }
+}
+
+/// The Lee method, at working distance `d`. The arcing current is taken to be the bolted fault current.
+pub struct Lee {
+    pub v_oc: ElectricPotential,
+    pub d: Length,
+}
+
+impl ArcFlashResult for LeeEAfb {
+    fn i_arc(&self) -> ElectricCurrent {
+        self.i_bf
+    }
+
+    fn t_arc(&self) -> Time {
+        self.t_arc
+    }
+
+    fn e(&self) -> RadiantExposure {
+        self.e
+    }
+
+    fn afb(&self) -> Length {
+        self.afb
+    }
+
+    fn e_at_distance(&self, d: Length) -> Result<RadiantExposure> {
+        check_distance(d)?;
+        Ok(LeeEAfb::e_at_distance(self, d))
+    }
+
+    fn distance_for_e(&self, e_b: RadiantExposure) -> Result<Length> {
+        check_energy(e_b)?;
+        Ok(LeeEAfb::distance_for_e(self, e_b))
+    }
+
+    fn diagnostics(&self) -> Vec<Warning> {
+        vec![Warning::LeeMethod]
+    }
+}
+
+impl ArcFlashMethod for Lee {
+    fn name(&self) -> String {
+        "Lee".to_string()
+    }
+
+    fn arcing_currents(
+        &self,
+        i_bf: ElectricCurrent,
+    ) -> Result<Vec<(ArcingCurrentCase, ElectricCurrent)>> {
+        Ok(vec![(ArcingCurrentCase::Full, i_bf)])
+    }
+
+    fn calculate(
+        &self,
+        i_bf: ElectricCurrent,
+        _case: ArcingCurrentCase,
+        t_arc: Time,
+    ) -> Result<Box<dyn ArcFlashResult>> {
+        Ok(Box::new(lee_e_afb(self.v_oc, i_bf, t_arc, self.d)?))
+    }
+}
+
+/// A DC arc at working distance `d`, from a source with voltage `v`.
+pub struct Dc {
+    pub v: ElectricPotential,
+    pub model: DcArcModel,
+    pub enclosure: DcEnclosure,
+    pub d: Length,
+}
+
+impl ArcFlashResult for DcArcFlash {
+    fn i_arc(&self) -> ElectricCurrent {
+        self.i_arc
+    }
+
+    fn t_arc(&self) -> Time {
+        self.t_arc
+    }
+
+    fn e(&self) -> RadiantExposure {
+        self.e
+    }
+
+    fn afb(&self) -> Length {
+        self.afb
+    }
+
+    fn e_at_distance(&self, d: Length) -> Result<RadiantExposure> {
+        check_distance(d)?;
+        Ok(DcArcFlash::e_at_distance(self, d))
+    }
+
+    fn distance_for_e(&self, e_b: RadiantExposure) -> Result<Length> {
+        check_energy(e_b)?;
+        Ok(DcArcFlash::distance_for_e(self, e_b))
+    }
+
+    fn diagnostics(&self) -> Vec<Warning> {
+        vec![Warning::DcArc]
+    }
+}
+
+impl ArcFlashMethod for Dc {
+    fn name(&self) -> String {
+        "DC".to_string()
+    }
+
+    fn arcing_currents(
+        &self,
+        i_bf: ElectricCurrent,
+    ) -> Result<Vec<(ArcingCurrentCase, ElectricCurrent)>> {
+        let source = DcSource { v: self.v, i_bf };
+        let (i_arc, _) = dc_arc_current(&source, self.model)?;
+        Ok(vec![(ArcingCurrentCase::Full, i_arc)])
+    }
+
+    fn calculate(
+        &self,
+        i_bf: ElectricCurrent,
+        _case: ArcingCurrentCase,
+        t_arc: Time,
+    ) -> Result<Box<dyn ArcFlashResult>> {
+        let source = DcSource { v: self.v, i_bf };
+        Ok(Box::new(dc_arc_flash(
+            &source,
+            self.model,
+            self.enclosure,
+            t_arc,
+            self.d,
+        )?))
+    }
+}
+
+/// The result of one arcing current case of `evaluate_method`.
+pub struct MethodCase {
+    pub case: ArcingCurrentCase,
+    pub result: Box<dyn ArcFlashResult>,
+    /// True if the clearing time was limited to the maximum arc duration.
+    pub capped: bool,
+}
+
+/// The results of all arcing current cases of a method, and the case that governs.
+pub struct MethodEvaluation {
+    pub method: String,
+    pub cases: Vec<MethodCase>,
+    governing: usize,
+}
+
+impl MethodEvaluation {
+    /// The result of `case`, if the method has that case.
+    pub fn case(&self, case: ArcingCurrentCase) -> Option<&MethodCase> {
+        self.cases.iter().find(|c| c.case == case)
+    }
+
+    /// The case with the highest incident energy (the first, if equal).
+    pub fn governing(&self) -> &MethodCase {
+        &self.cases[self.governing]
+    }
+}
+
+/// Calculate every arcing current case of `method` for bolted fault current `i_bf`, with the clearing time of each
+/// from `clearing` (e.g. a protective device), and determine which case governs.
+///
+/// `max_arc_duration` is as for `Scenario::max_arc_duration`.
+pub fn evaluate_method<C: ClearingTimeSource + ?Sized>(
+    method: &dyn ArcFlashMethod,
+    i_bf: ElectricCurrent,
+    clearing: &C,
+    max_arc_duration: Option<Time>,
+) -> Result<MethodEvaluation> {
+    let mut cases: Vec<MethodCase> = Vec::new();
+    let mut governing = 0;
+
+    for (case, i) in method.arcing_currents(i_bf)? {
+        let (t_arc, capped) = arc_duration(clearing.clearing_time(case, i), max_arc_duration, i)?;
+        let result = method.calculate(i_bf, case, t_arc)?;
+        if let Some(g) = cases.get(governing) {
+            if result.e() > g.result.e() {
+                governing = cases.len();
+            }
+        }
+        cases.push(MethodCase {
+            case,
+            result,
+            capped,
+        });
+    }
+
+    if cases.is_empty() {
+        return Err(format_err!("{} has no arcing current cases", method.name()));
+    }
+
+    Ok(MethodEvaluation {
+        method: method.name(),
+        cases,
+        governing,
+    })
+}
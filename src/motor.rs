@@ -0,0 +1,111 @@
+use anyhow::{format_err, Result};
+use std::f64::consts::PI;
+use uom::si::electric_potential::volt;
+use uom::si::f64::{ElectricCurrent, ElectricPotential, Frequency, Power, Time};
+use uom::si::frequency::hertz;
+use uom::si::power::kilowatt;
+use uom::si::time::second;
+
+use crate::profile::FaultCurrentProfile;
+
+/// Rating of a motor, for its contribution to a fault.
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub enum MotorRating {
+    /// Apparent power in kVA.
+    Kva(f64),
+    /// Horsepower. For induction motors 1 hp is taken to be approximately 1 kVA.
+    Hp(f64),
+}
+
+impl MotorRating {
+    pub fn kva(&self) -> f64 {
+        match self {
+            MotorRating::Kva(kva) => *kva,
+            MotorRating::Hp(hp) => *hp,
+        }
+    }
+}
+
+/// An induction motor (or group of motors) that contributes to a fault at its bus.
+///
+/// The contribution starts at the subtransient current `I'' = I_rated / X''` and decays exponentially with the time
+/// constant `T = (X/R) / (2 π f)`, as the motor's stored magnetic energy is dissipated. IEEE 1584-2018 s6.6 requires
+/// motor contribution to be included in the bolted fault current.
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub struct InductionMotor {
+    pub rating: MotorRating,
+    /// Rated voltage (phase to phase).
+    pub v: ElectricPotential,
+    /// Subtransient reactance X'', per unit on the motor base.
+    pub x_subtransient: f64,
+    /// X/R ratio of the motor.
+    pub x_r: f64,
+    pub frequency: Frequency,
+}
+
+impl InductionMotor {
+    fn check(&self) -> Result<()> {
+        if !(self.rating.kva() > 0.0 && self.rating.kva().is_finite()) {
+            return Err(format_err!(
+                "motor rating must be positive: {} kVA",
+                self.rating.kva()
+            ));
+        }
+        if !(self.x_subtransient > 0.0 && self.x_subtransient.is_finite()) {
+            return Err(format_err!(
+                "motor subtransient reactance must be positive: {} pu",
+                self.x_subtransient
+            ));
+        }
+        if !(self.x_r > 0.0 && self.x_r.is_finite()) {
+            return Err(format_err!("motor X/R must be positive: {}", self.x_r));
+        }
+        if !(self.v.get::<volt>() > 0.0 && self.frequency.get::<hertz>() > 0.0) {
+            return Err(format_err!("motor voltage and frequency must be positive"));
+        }
+        Ok(())
+    }
+
+    /// Rated current.
+    pub fn i_rated(&self) -> ElectricCurrent {
+        let s = Power::new::<kilowatt>(self.rating.kva());
+        s / (3.0_f64.sqrt() * self.v)
+    }
+
+    /// Initial (subtransient) symmetrical contribution to a three-phase fault.
+    pub fn i_subtransient(&self) -> ElectricCurrent {
+        self.i_rated() / self.x_subtransient
+    }
+
+    /// Time constant of the decay of the contribution.
+    pub fn time_constant(&self) -> Time {
+        Time::new::<second>(self.x_r / (2.0 * PI * self.frequency.get::<hertz>()))
+    }
+
+    /// Symmetrical contribution to a three-phase fault at time `t` after fault inception.
+    pub fn current(&self, t: Time) -> ElectricCurrent {
+        let t = t.max(Time::new::<second>(0.0));
+        self.i_subtransient() * (-(t / self.time_constant()).value).exp()
+    }
+}
+
+/// The bolted fault current at a bus, from the source (utility) current `i_bf_source` plus the decaying contribution
+/// of `motors`, sampled every `step` up to `duration`.
+///
+/// The result can be used with `multistep_profile`.
+pub fn motor_contribution_profile(
+    i_bf_source: ElectricCurrent,
+    motors: &[InductionMotor],
+    duration: Time,
+    step: Time,
+) -> Result<FaultCurrentProfile> {
+    for motor in motors {
+        motor.check()?;
+    }
+
+    FaultCurrentProfile::sample(duration, step, |t| {
+        motors
+            .iter()
+            .fold(i_bf_source, |i_bf, motor| i_bf + motor.current(t))
+    })
+}
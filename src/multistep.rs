@@ -1,7 +1,9 @@
 use uom::si::f64::{Length, RadiantExposure};
 
 use crate::equations::{intermediate_afb_from_e, interpolate};
-use crate::{Cubicle, EAfb, NominalVoltage};
+use crate::error::ArcFlashError;
+use crate::warning::{e_afb_warnings, Warning};
+use crate::{Cubicle, DistanceBasis, EAfb, EAfbHV, EAfbLV, NominalVoltage};
 
 /// Calculates the total energy and total arc flash boundary for a multistep calculation.
 ///
@@ -21,22 +23,84 @@ use crate::{Cubicle, EAfb, NominalVoltage};
 /// The final AFB is then interpolated from the intermediate AFB's.
 ///
 /// For LV, there are no intermediate values / interpolation so we can just work out the AFB based on total_E.
+///
+/// # Panics
+///
+/// If the steps were not calculated for the cubicle `c`, e.g. if LV steps are given for a HV cubicle; see
+/// `multistep_total`.
 pub fn multistep_e_and_afb(c: &Cubicle, calc_steps: &[EAfb]) -> (RadiantExposure, Length) {
+    let total = multistep_total(c, calc_steps).expect("steps calculated for the cubicle");
+    (total.e(), total.afb())
+}
+
+/// As for `multistep_e_and_afb`, with the warnings that apply to the total (e.g. where the total arc duration
+/// exceeds 2 seconds), and steps that were not calculated for `c` being an error.
+pub fn multistep_e_and_afb_with_warnings(
+    c: &Cubicle,
+    calc_steps: &[EAfb],
+) -> Result<(RadiantExposure, Length, Vec<Warning>), ArcFlashError> {
+    let total = multistep_total(c, calc_steps)?;
+    Ok((total.e(), total.afb(), total.warnings().to_vec()))
+}
+
+/// Combines the steps of a multistep calculation into a single `EAfb`.
+///
+/// `T_arc` and the (intermediate) energies are the sums across all steps, and the warnings are for the total
+/// `T_arc`. The (intermediate) AFB's are worked out
+/// from the total energies, as for `multistep_e_and_afb`. The result can be used with `EAfb::e_at_distance` and
+/// `EAfb::distance_for_e`.
+///
+/// Steps that were not calculated for the cubicle `c` (e.g. LV steps for a HV cubicle) are an error.
+pub fn multistep_total(c: &Cubicle, calc_steps: &[EAfb]) -> Result<EAfb, ArcFlashError> {
+    let t_arc = calc_steps.iter().map(|e_afb| e_afb.t_arc()).sum();
     let total_e = calc_steps.iter().map(|e_afb| e_afb.e()).sum();
 
-    let total_afb = if c.hv {
-        let total_e_600 = calc_steps.iter().map(|e_afb| e_afb.hv().e_600).sum();
-        let total_e_2700 = calc_steps.iter().map(|e_afb| e_afb.hv().e_2700).sum();
-        let total_e_14300 = calc_steps.iter().map(|e_afb| e_afb.hv().e_14300).sum();
+    let total = if c.hv {
+        let mut steps = Vec::with_capacity(calc_steps.len());
+        for e_afb in calc_steps {
+            match e_afb {
+                EAfb::HighVoltage(e_afb) => steps.push(e_afb),
+                EAfb::LowVoltage(_) => {
+                    return Err(ArcFlashError::MismatchedResult { v_oc: c.v_oc })
+                }
+            }
+        }
+        let total_e_600 = steps.iter().map(|e_afb| e_afb.e_600).sum();
+        let total_e_2700 = steps.iter().map(|e_afb| e_afb.e_2700).sum();
+        let total_e_14300 = steps.iter().map(|e_afb| e_afb.e_14300).sum();
 
         let afb_600 = intermediate_afb_from_e(c, NominalVoltage::V600, total_e_600);
         let afb_2700 = intermediate_afb_from_e(c, NominalVoltage::V2700, total_e_2700);
         let afb_14300 = intermediate_afb_from_e(c, NominalVoltage::V14300, total_e_14300);
 
-        interpolate!(c, afb_600, afb_2700, afb_14300)
+        EAfb::HighVoltage(EAfbHV {
+            t_arc,
+            afb_14300,
+            afb_2700,
+            afb_600,
+            afb: interpolate!(c.v_oc, afb_600, afb_2700, afb_14300)?,
+            e_14300: total_e_14300,
+            e_2700: total_e_2700,
+            e_600: total_e_600,
+            e: total_e,
+            basis: DistanceBasis::new(c),
+            warnings: e_afb_warnings(c, t_arc),
+        })
     } else {
-        intermediate_afb_from_e(c, NominalVoltage::V600, total_e)
+        if calc_steps
+            .iter()
+            .any(|e_afb| matches!(e_afb, EAfb::HighVoltage(_)))
+        {
+            return Err(ArcFlashError::MismatchedResult { v_oc: c.v_oc });
+        }
+        EAfb::LowVoltage(EAfbLV {
+            t_arc,
+            afb: intermediate_afb_from_e(c, NominalVoltage::V600, total_e),
+            e: total_e,
+            basis: DistanceBasis::new(c),
+            warnings: e_afb_warnings(c, t_arc),
+        })
     };
 
-    (total_e, total_afb)
+    Ok(total)
 }
@@ -0,0 +1,347 @@
+use anyhow::{format_err, Result};
+use std::fmt::{Display, Formatter};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use uom::si::electric_current::kiloampere;
+use uom::si::electric_potential::{kilovolt, volt};
+use uom::si::f64::{ElectricCurrent, ElectricPotential, Time};
+use uom::si::length::millimeter;
+use uom::si::radiant_exposure::joule_per_square_centimeter;
+use uom::si::time::millisecond;
+
+use crate::cubicle::Cubicle;
+use crate::method::{evaluate_method, ArcFlashMethod, Ieee2018, MethodEvaluation};
+use crate::protection::ProtectiveDevice;
+use crate::scenario::{ArcingCurrentCase, ClearingTimeSource};
+use crate::short_circuit::{RadialFeed, SeriesElement, ShortCircuit, UtilitySource};
+
+/// Identifies a bus in a `Network`. The id of a bus is only valid for the network it was added to.
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
+pub struct BusId {
+    network: usize,
+    index: usize,
+}
+
+/// Source of the ids of networks, so that a `BusId` of one network is not taken to be a bus of another.
+static NEXT_NETWORK: AtomicUsize = AtomicUsize::new(0);
+
+/// A protective device, with a name for reporting.
+pub struct NamedDevice {
+    pub name: String,
+    pub device: Box<dyn ProtectiveDevice>,
+}
+
+/// A bus in a radial network.
+pub struct Bus {
+    pub name: String,
+    /// The upstream bus, or `None` if the bus is supplied directly by the source.
+    pub parent: Option<BusId>,
+    /// Transformers and feeders between the upstream bus (or the source) and this bus, in order from upstream.
+    pub connection: Vec<SeriesElement>,
+    pub cubicle: Cubicle,
+    /// The protective device that supplies the bus, e.g. its main breaker. The device sees the current at the
+    /// voltage of this bus, i.e. it is on the load side of any transformer in `connection`. (A transformer primary
+    /// device can be modelled as the device of a bus at the transformer primary terminals.)
+    pub device: Option<NamedDevice>,
+}
+
+/// A radial network: a tree of buses supplied by a utility source.
+pub struct Network {
+    pub source: UtilitySource,
+    id: usize,
+    buses: Vec<Bus>,
+    /// Maximum arc duration, see `Scenario::max_arc_duration`.
+    pub max_arc_duration: Option<Time>,
+}
+
+impl Network {
+    pub fn new(source: UtilitySource) -> Self {
+        Self {
+            source,
+            id: NEXT_NETWORK.fetch_add(1, Ordering::Relaxed),
+            buses: Vec::new(),
+            max_arc_duration: None,
+        }
+    }
+
+    /// Adds a bus. Its upstream bus must already have been added.
+    pub fn add_bus(&mut self, bus: Bus) -> Result<BusId> {
+        if let Some(parent) = bus.parent {
+            if self.bus(parent).is_none() {
+                return Err(format_err!(
+                    "upstream bus of {} is not in the network",
+                    bus.name
+                ));
+            }
+        }
+        self.buses.push(bus);
+        Ok(self.id(self.buses.len() - 1))
+    }
+
+    fn id(&self, index: usize) -> BusId {
+        BusId {
+            network: self.id,
+            index,
+        }
+    }
+
+    /// The bus `id`, or `None` if it is not a bus of this network.
+    pub fn bus(&self, id: BusId) -> Option<&Bus> {
+        if id.network == self.id {
+            self.buses.get(id.index)
+        } else {
+            None
+        }
+    }
+
+    fn checked_bus(&self, id: BusId) -> Result<&Bus> {
+        self.bus(id)
+            .ok_or_else(|| format_err!("bus {:?} is not in the network", id))
+    }
+
+    pub fn buses(&self) -> &[Bus] {
+        &self.buses
+    }
+
+    /// The buses from the source to `id`, inclusive.
+    pub fn path(&self, id: BusId) -> Result<Vec<BusId>> {
+        let mut path = vec![id];
+        let mut bus = self.checked_bus(id)?;
+        while let Some(parent) = bus.parent {
+            path.push(parent);
+            // The upstream buses were checked by `add_bus`.
+            bus = &self.buses[parent.index];
+        }
+        path.reverse();
+        Ok(path)
+    }
+
+    /// The three-phase bolted fault current at bus `id`.
+    pub fn short_circuit(&self, id: BusId) -> Result<ShortCircuit> {
+        RadialFeed {
+            source: self.source,
+            elements: self
+                .path(id)?
+                .into_iter()
+                .flat_map(|id| self.buses[id.index].connection.iter().copied())
+                .collect(),
+        }
+        .fault()
+    }
+
+    /// Voltage base of bus `id`: the source voltage carried through the transformer turns ratios.
+    pub fn voltage(&self, id: BusId) -> Result<ElectricPotential> {
+        let mut v = self.source.v;
+        for id in self.path(id)? {
+            for element in &self.buses[id.index].connection {
+                if let SeriesElement::Transformer(tx) = element {
+                    v = tx.secondary_base(v);
+                }
+            }
+        }
+        Ok(v)
+    }
+
+    /// The devices that can clear a fault at bus `id`, with the ratio of the current they see to the current at
+    /// the bus. If `line_side` is true, the fault is on the line side of the bus's own device, which does not see it.
+    fn devices(&self, id: BusId, line_side: bool) -> Result<PathDevices<'_>> {
+        let v = self.voltage(id)?;
+        let mut devices = Vec::new();
+        for path_id in self.path(id)? {
+            if line_side && path_id == id {
+                continue;
+            }
+            if let Some(device) = &self.buses[path_id.index].device {
+                devices.push((device, (v / self.voltage(path_id)?).value));
+            }
+        }
+
+        Ok(PathDevices { devices })
+    }
+
+    fn evaluate(
+        &self,
+        id: BusId,
+        short_circuit: &ShortCircuit,
+        line_side: bool,
+        method: &dyn ArcFlashMethod,
+    ) -> Result<ClearedFault> {
+        let devices = self.devices(id, line_side)?;
+        let evaluation =
+            evaluate_method(method, short_circuit.i_bf, &devices, self.max_arc_duration)?;
+        let governing = evaluation.governing();
+        let device = if governing.capped {
+            None
+        } else {
+            devices
+                .clearing(governing.result.i_arc())
+                .map(|(device, _)| device.name.clone())
+        };
+
+        Ok(ClearedFault { device, evaluation })
+    }
+
+    fn study_bus(&self, id: BusId, method: &MethodFor) -> BusResult {
+        let bus = &self.buses[id.index];
+        let method = method(&bus.cubicle);
+        let short_circuit = self.short_circuit(id).and_then(|short_circuit| {
+            let ratio = (bus.cubicle.v_oc / short_circuit.v).value;
+            if (0.9..=1.1).contains(&ratio) {
+                Ok(short_circuit)
+            } else {
+                Err(format_err!(
+                    "cubicle voltage {} V does not match the bus voltage {} V",
+                    bus.cubicle.v_oc.get::<volt>(),
+                    short_circuit.v.get::<volt>()
+                ))
+            }
+        });
+
+        let (load_side, line_side) = match &short_circuit {
+            Ok(short_circuit) => (
+                self.evaluate(id, short_circuit, false, method.as_ref()),
+                bus.device
+                    .as_ref()
+                    .map(|_| self.evaluate(id, short_circuit, true, method.as_ref())),
+            ),
+            Err(err) => (Err(format_err!("{}", err)), None),
+        };
+
+        BusResult {
+            name: bus.name.clone(),
+            short_circuit,
+            load_side,
+            line_side,
+        }
+    }
+
+    /// Calculate the fault current, arcing current, clearing device and incident energy at every bus, by IEEE
+    /// 1584-2018.
+    pub fn study(&self) -> NetworkStudy {
+        self.study_with(&|cubicle| Box::new(Ieee2018 { cubicle }))
+    }
+
+    /// Calculate every bus as `study`, by the method that `method` returns for the cubicle of each bus.
+    pub fn study_with(&self, method: &MethodFor) -> NetworkStudy {
+        NetworkStudy {
+            buses: (0..self.buses.len())
+                .map(|index| self.study_bus(self.id(index), method))
+                .collect(),
+        }
+    }
+}
+
+/// The method of calculation for a cubicle, e.g. `|cubicle| Box::new(Ieee2018 { cubicle })`.
+pub type MethodFor<'a> = dyn Fn(&Cubicle) -> Box<dyn ArcFlashMethod + '_> + 'a;
+
+/// The devices upstream of a fault, each with the ratio of the current it sees to the current at the fault.
+struct PathDevices<'a> {
+    devices: Vec<(&'a NamedDevice, f64)>,
+}
+
+impl PathDevices<'_> {
+    /// The first device to clear a fault with arcing current `i_arc`, and its clearing time.
+    fn clearing(&self, i_arc: ElectricCurrent) -> Option<(&NamedDevice, Time)> {
+        self.devices
+            .iter()
+            .filter_map(|(device, ratio)| {
+                device
+                    .device
+                    .clearing_time(i_arc * *ratio)
+                    .map(|t| (*device, t))
+            })
+            .fold(None, |first, (device, t)| match first {
+                Some((_, t_first)) if t_first <= t => first,
+                _ => Some((device, t)),
+            })
+    }
+}
+
+impl ClearingTimeSource for PathDevices<'_> {
+    fn clearing_time(&self, _case: ArcingCurrentCase, i_arc: ElectricCurrent) -> Option<Time> {
+        self.clearing(i_arc).map(|(_, t)| t)
+    }
+}
+
+/// Results of a fault at one location, and the device that clears it.
+pub struct ClearedFault {
+    /// Name of the device that clears the governing case, or `None` if it is not cleared within the maximum arc
+    /// duration.
+    pub device: Option<String>,
+    pub evaluation: MethodEvaluation,
+}
+
+/// Results for one bus of a `Network`.
+pub struct BusResult {
+    pub name: String,
+    pub short_circuit: Result<ShortCircuit>,
+    /// A fault on the bus, on the load side of its device.
+    pub load_side: Result<ClearedFault>,
+    /// A fault on the line side of the bus's own device (e.g. at the line terminals of a main breaker), which must
+    /// be cleared by an upstream device. `None` if the bus has no device.
+    pub line_side: Option<Result<ClearedFault>>,
+}
+
+/// Results of a `Network` study, in the order the buses were added.
+pub struct NetworkStudy {
+    pub buses: Vec<BusResult>,
+}
+
+impl Display for NetworkStudy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{:<16} {:>8} {:>9} {:<9} {:<16} {:>9} {:>10} {:>10} {:>9}",
+            "Bus",
+            "V (kV)",
+            "Ibf (kA)",
+            "Location",
+            "Device",
+            "Iarc (kA)",
+            "t (ms)",
+            "E (J/cm²)",
+            "AFB (mm)"
+        )?;
+
+        for bus in &self.buses {
+            let (v, i_bf) = match &bus.short_circuit {
+                Ok(sc) => (
+                    format!("{:.3}", sc.v.get::<kilovolt>()),
+                    format!("{:.2}", sc.i_bf.get::<kiloampere>()),
+                ),
+                Err(_) => (String::new(), String::new()),
+            };
+            let rows = std::iter::once(("load", Some(&bus.load_side)))
+                .chain(std::iter::once(("line", bus.line_side.as_ref())));
+
+            for (location, result) in rows {
+                match result {
+                    Some(Ok(fault)) => {
+                        let case = &fault.evaluation.governing().result;
+                        writeln!(
+                            f,
+                            "{:<16} {:>8} {:>9} {:<9} {:<16} {:>9.2} {:>10.1} {:>10.2} {:>9.0}",
+                            bus.name,
+                            v,
+                            i_bf,
+                            location,
+                            fault.device.as_deref().unwrap_or("(not cleared)"),
+                            case.i_arc().get::<kiloampere>(),
+                            case.t_arc().get::<millisecond>(),
+                            case.e().get::<joule_per_square_centimeter>(),
+                            case.afb().get::<millimeter>()
+                        )?;
+                    }
+                    Some(Err(err)) => {
+                        writeln!(
+                            f,
+                            "{:<16} {:>8} {:>9} {:<9} error: {}",
+                            bus.name, v, i_bf, location, err
+                        )?;
+                    }
+                    None => {}
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,131 @@
+use std::fmt::{Display, Formatter};
+use uom::fmt::DisplayStyle::Abbreviation;
+use uom::si::f64::RadiantExposure;
+
+use crate::common::units::calorie_per_square_centimeter as cal_per_sq_cm;
+use crate::e_afb::EAfb;
+
+/// A PPE category, or clothing system, and its arc rating.
+#[derive(Clone, Debug)]
+pub struct PpeRating {
+    pub name: String,
+    pub arc_rating: RadiantExposure,
+}
+
+impl PpeRating {
+    pub fn new(name: &str, arc_rating: RadiantExposure) -> Self {
+        Self {
+            name: name.to_string(),
+            arc_rating,
+        }
+    }
+}
+
+/// A table of PPE categories, or site-specific clothing systems, that may be selected from.
+pub struct PpeTable {
+    ratings: Vec<PpeRating>,
+}
+
+impl PpeTable {
+    pub fn new(mut ratings: Vec<PpeRating>) -> Self {
+        ratings.sort_by(|a, b| {
+            a.arc_rating
+                .get::<cal_per_sq_cm>()
+                .total_cmp(&b.arc_rating.get::<cal_per_sq_cm>())
+        });
+        Self { ratings }
+    }
+
+    /// PPE categories 1 to 4 of NFPA 70E Table 130.7(C)(15)(c).
+    pub fn nfpa_70e() -> Self {
+        Self::new(vec![
+            PpeRating::new("PPE Category 1", RadiantExposure::new::<cal_per_sq_cm>(4.0)),
+            PpeRating::new("PPE Category 2", RadiantExposure::new::<cal_per_sq_cm>(8.0)),
+            PpeRating::new(
+                "PPE Category 3",
+                RadiantExposure::new::<cal_per_sq_cm>(25.0),
+            ),
+            PpeRating::new(
+                "PPE Category 4",
+                RadiantExposure::new::<cal_per_sq_cm>(40.0),
+            ),
+        ])
+    }
+
+    pub fn ratings(&self) -> &[PpeRating] {
+        &self.ratings
+    }
+
+    /// Select the lowest rated PPE with an arc rating of at least the incident energy `e`.
+    pub fn select(&self, e: RadiantExposure) -> PpeSelection {
+        PpeSelection {
+            e,
+            ppe: self.ratings.iter().find(|r| r.arc_rating >= e).cloned(),
+        }
+    }
+
+    /// Select PPE for the incident energy of `e_afb`. For a multistep calculation use `multistep_total`.
+    pub fn select_for(&self, e_afb: &EAfb) -> PpeSelection {
+        self.select(e_afb.e())
+    }
+}
+
+impl Default for PpeTable {
+    fn default() -> Self {
+        Self::nfpa_70e()
+    }
+}
+
+/// Selected PPE for an incident energy.
+pub struct PpeSelection {
+    /// Incident energy, and so the minimum arc rating required.
+    pub e: RadiantExposure,
+    /// The lowest rated PPE that is adequate, or `None` if no PPE in the table applies.
+    pub ppe: Option<PpeRating>,
+}
+
+impl PpeSelection {
+    /// The minimum arc rating of PPE required, i.e. the incident energy.
+    pub fn min_arc_rating(&self) -> RadiantExposure {
+        self.e
+    }
+
+    /// True if the incident energy is at least 1.2 cal/cm², so that arc-rated PPE is required.
+    pub fn arc_rated_ppe_required(&self) -> bool {
+        self.e >= RadiantExposure::new::<cal_per_sq_cm>(1.2)
+    }
+
+    /// True if the incident energy exceeds 40 cal/cm².
+    pub fn exceeds_40(&self) -> bool {
+        self.e > RadiantExposure::new::<cal_per_sq_cm>(40.0)
+    }
+
+    /// True if no PPE in the table is adequate for the incident energy.
+    pub fn no_category(&self) -> bool {
+        self.ppe.is_none()
+    }
+}
+
+/// Select PPE from the NFPA 70E PPE categories for the incident energy of `e_afb`.
+pub fn select_ppe(e_afb: &EAfb) -> PpeSelection {
+    PpeTable::nfpa_70e().select_for(e_afb)
+}
+
+impl Display for PpeSelection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "E = {}, PPE = ",
+            self.e.into_format_args(cal_per_sq_cm, Abbreviation)
+        )?;
+        match &self.ppe {
+            Some(ppe) => write!(
+                f,
+                "{} ({})",
+                ppe.name,
+                ppe.arc_rating.into_format_args(cal_per_sq_cm, Abbreviation)
+            ),
+            None => write!(f, "none applies"),
+        }
+    }
+}
@@ -0,0 +1,343 @@
+use anyhow::{format_err, Result};
+use uom::si::electric_current::ampere;
+use uom::si::f64::{ElectricCurrent, Length, RadiantExposure, Time};
+use uom::si::time::second;
+
+use crate::cubicle::Cubicle;
+use crate::e_afb::{e_afb, EAfb};
+use crate::error::ArcFlashError;
+use crate::i_arc::{i_arc, IArc};
+use crate::multistep::multistep_total;
+use crate::protection::ProtectiveDevice;
+use crate::warning::Warning;
+
+/// Maximum number of steps a fault current profile may be divided into, e.g. by a very small `max_step`.
+pub const MAX_PROFILE_STEPS: usize = 100_000;
+
+/// A period of constant bolted fault current.
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub struct ProfileSegment {
+    pub i_bf: ElectricCurrent,
+    pub duration: Time,
+}
+
+/// The bolted fault current at a time after fault inception.
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub struct ProfileSample {
+    pub time: Time,
+    pub i_bf: ElectricCurrent,
+}
+
+/// Bolted fault current that changes over the duration of the fault, e.g. due to decaying motor contribution or
+/// upstream devices that open in sequence.
+pub enum FaultCurrentProfile {
+    /// Periods of constant current, in order from fault inception. The last current continues after the end of the
+    /// last segment.
+    Segments(Vec<ProfileSegment>),
+    /// An envelope sampled at increasing times from fault inception, linear between samples. The envelope is
+    /// divided into steps of at most `max_step`, with the current of each step taken at its midpoint. Before the
+    /// first sample the first current applies, and after the last sample the last current continues.
+    Sampled {
+        samples: Vec<ProfileSample>,
+        max_step: Time,
+    },
+}
+
+impl FaultCurrentProfile {
+    /// A constant bolted fault current.
+    pub fn constant(i_bf: ElectricCurrent) -> Self {
+        FaultCurrentProfile::Segments(vec![ProfileSegment {
+            i_bf,
+            duration: Time::new::<second>(0.0),
+        }])
+    }
+
+    /// Samples the current given by `f` at times `0, step, 2 * step, ...` up to `duration`.
+    pub fn sample(duration: Time, step: Time, f: impl Fn(Time) -> ElectricCurrent) -> Result<Self> {
+        if !(positive(step) && duration.is_finite()) {
+            return Err(format_err!(
+                "sample step must be positive and duration finite"
+            ));
+        }
+        let n = step_count(duration, step)?;
+        let samples = (0..=n)
+            .map(|k| {
+                let time = if k == n { duration } else { step * k as f64 };
+                ProfileSample {
+                    time,
+                    i_bf: f(time),
+                }
+            })
+            .collect();
+
+        Ok(FaultCurrentProfile::Sampled {
+            samples,
+            max_step: step,
+        })
+    }
+
+    /// The bolted fault current at time `t` after fault inception.
+    pub fn i_bf_at(&self, t: Time) -> Option<ElectricCurrent> {
+        match self {
+            FaultCurrentProfile::Segments(segments) => {
+                let mut end = Time::new::<second>(0.0);
+                for segment in segments {
+                    end += segment.duration;
+                    if t < end {
+                        return Some(segment.i_bf);
+                    }
+                }
+                segments.last().map(|segment| segment.i_bf)
+            }
+            FaultCurrentProfile::Sampled { samples, .. } => {
+                let first = samples.first()?;
+                let last = samples.last()?;
+                if t <= first.time {
+                    return Some(first.i_bf);
+                }
+                if t >= last.time {
+                    return Some(last.i_bf);
+                }
+                let k = samples.partition_point(|s| s.time <= t);
+                let (s0, s1) = (&samples[k - 1], &samples[k]);
+                let x = ((t - s0.time) / (s1.time - s0.time)).value;
+                Some(s0.i_bf + (s1.i_bf - s0.i_bf) * x)
+            }
+        }
+    }
+
+    /// Divides the profile into periods of constant current, up to a total of `duration` (the clearing time).
+    pub fn segments(&self, duration: Time) -> Result<Vec<ProfileSegment>> {
+        let zero = Time::new::<second>(0.0);
+        if !(duration >= zero && duration.is_finite()) {
+            return Err(format_err!(
+                "duration must be positive and finite: {} s",
+                duration.get::<second>()
+            ));
+        }
+
+        // Boundaries of the steps, from fault inception.
+        let mut times = vec![zero];
+        match self {
+            FaultCurrentProfile::Segments(segments) => {
+                if segments.is_empty() {
+                    return Err(format_err!("fault current profile has no segments"));
+                }
+                let mut end = zero;
+                for segment in segments {
+                    if !(segment.duration >= zero && segment.duration.is_finite()) {
+                        return Err(format_err!(
+                            "segment duration must not be negative: {} s",
+                            segment.duration.get::<second>()
+                        ));
+                    }
+                    end += segment.duration;
+                    if end > zero && end < duration {
+                        times.push(end);
+                    }
+                }
+            }
+            FaultCurrentProfile::Sampled { samples, max_step } => {
+                if samples.is_empty() {
+                    return Err(format_err!("fault current profile has no samples"));
+                }
+                if !(positive(*max_step) && max_step.is_finite()) {
+                    return Err(format_err!("maximum step must be positive"));
+                }
+                if samples.windows(2).any(|w| w[0].time >= w[1].time) {
+                    return Err(format_err!("sample times must be increasing"));
+                }
+                for sample in samples {
+                    if sample.time > zero && sample.time < duration {
+                        times.push(sample.time);
+                    }
+                }
+            }
+        }
+        times.push(duration);
+        times.dedup();
+
+        let max_step = match self {
+            FaultCurrentProfile::Sampled { max_step, .. } => Some(*max_step),
+            FaultCurrentProfile::Segments(_) => None,
+        };
+
+        let mut steps = Vec::new();
+        for w in times.windows(2) {
+            let (start, end) = (w[0], w[1]);
+            let n = match max_step {
+                Some(max_step) => step_count(end - start, max_step)?,
+                None => 1,
+            };
+            if steps.len() + n > MAX_PROFILE_STEPS {
+                return Err(too_many_steps());
+            }
+            let dt = (end - start) / n as f64;
+            for k in 0..n {
+                let mid = start + dt * (k as f64 + 0.5);
+                // The profile is not empty, so there is always a current.
+                let i_bf = self.i_bf_at(mid).unwrap();
+                steps.push(ProfileSegment { i_bf, duration: dt });
+            }
+        }
+
+        Ok(steps)
+    }
+}
+
+fn positive(t: Time) -> bool {
+    t.get::<second>() > 0.0
+}
+
+/// Number of steps of at most `step` in `duration`, allowing for rounding in the division. More than
+/// `MAX_PROFILE_STEPS` is an error.
+fn step_count(duration: Time, step: Time) -> Result<usize> {
+    let n = ((duration / step).value - 1e-9).ceil().max(1.0);
+    if n > MAX_PROFILE_STEPS as f64 {
+        return Err(too_many_steps());
+    }
+    Ok(n as usize)
+}
+
+fn too_many_steps() -> anyhow::Error {
+    format_err!(
+        "fault current profile has more than {} steps: increase the step size",
+        MAX_PROFILE_STEPS
+    )
+}
+
+/// The arcing current for bolted fault current `i_bf`, or `None` if `i_bf` is below the range of the model.
+fn i_arc_in_range(c: &Cubicle, i_bf: ElectricCurrent, reduced: bool) -> Result<Option<IArc>> {
+    match i_arc(c, i_bf, reduced) {
+        Ok(i_arc) => Ok(Some(i_arc)),
+        Err(ArcFlashError::IbfOutOfRange { i_bf, min, .. }) if i_bf < min => Ok(None),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// One step of a multistep calculation from a fault current profile.
+pub struct ProfileStep {
+    /// Time of the start of the step, from fault inception.
+    pub start: Time,
+    pub i_arc: IArc,
+    pub e_afb: EAfb,
+}
+
+/// Results of a multistep calculation from a fault current profile.
+pub struct ProfileResult {
+    pub steps: Vec<ProfileStep>,
+    /// The combined result of all steps, from `multistep_total`.
+    pub total: EAfb,
+    /// Warnings of the total, and `Warning::ProfileBelowRange` if any steps were skipped.
+    pub warnings: Vec<Warning>,
+}
+
+impl ProfileResult {
+    pub fn e(&self) -> RadiantExposure {
+        self.total.e()
+    }
+
+    pub fn afb(&self) -> Length {
+        self.total.afb()
+    }
+}
+
+/// Calculate the incident energy and AFB for a bolted fault current that follows `profile` until the fault is
+/// cleared at `duration`.
+///
+/// The profile is divided into periods of constant current, and the arcing current (full, or reduced if `reduced`
+/// is true) and incident energy are calculated for each, then combined with `multistep_total`. Periods with no
+/// current (e.g. after an upstream device has opened) are skipped. Periods with a current below the range of the
+/// model are taken to give no incident energy, with a `Warning::ProfileBelowRange`.
+pub fn multistep_profile(
+    c: &Cubicle,
+    profile: &FaultCurrentProfile,
+    reduced: bool,
+    duration: Time,
+) -> Result<ProfileResult> {
+    let mut steps = Vec::new();
+    let mut start = Time::new::<second>(0.0);
+    // Lowest current and total duration of the steps below the range of the model.
+    let mut below_range: Option<(ElectricCurrent, Time)> = None;
+
+    for segment in profile.segments(duration)? {
+        if segment.i_bf > ElectricCurrent::new::<ampere>(0.0)
+            && segment.duration > Time::new::<second>(0.0)
+        {
+            match i_arc_in_range(c, segment.i_bf, reduced)? {
+                Some(i_arc) => {
+                    let e_afb = e_afb(c, i_arc.clone(), segment.duration);
+                    steps.push(ProfileStep {
+                        start,
+                        i_arc,
+                        e_afb,
+                    });
+                }
+                None => {
+                    below_range = Some(match below_range {
+                        Some((i_bf, t)) if i_bf < segment.i_bf => (i_bf, t + segment.duration),
+                        Some((_, t)) => (segment.i_bf, t + segment.duration),
+                        None => (segment.i_bf, segment.duration),
+                    });
+                }
+            }
+        }
+        start += segment.duration;
+    }
+
+    let total = multistep_total(
+        c,
+        &steps
+            .iter()
+            .map(|step| step.e_afb.clone())
+            .collect::<Vec<_>>(),
+    )?;
+
+    let mut warnings = total.warnings().to_vec();
+    if let Some((i_bf, duration)) = below_range {
+        warnings.push(Warning::ProfileBelowRange { i_bf, duration });
+    }
+
+    Ok(ProfileResult {
+        steps,
+        total,
+        warnings,
+    })
+}
+
+/// The time at which `device` clears a fault with a bolted fault current that follows `profile`, or `None` if it
+/// does not clear within `max_duration`.
+///
+/// The device sees the arcing current (full, or reduced if `reduced` is true) of each period of constant current.
+/// As the current changes, the device progresses towards operating at the rate `1 / t(I_arc)`, where `t(I_arc)` is
+/// its clearing time at that current, and operates when the progress reaches 1. Periods with a current below the
+/// range of the model do not progress the device.
+pub fn profile_clearing_time(
+    c: &Cubicle,
+    profile: &FaultCurrentProfile,
+    reduced: bool,
+    device: &dyn ProtectiveDevice,
+    max_duration: Time,
+) -> Result<Option<Time>> {
+    let mut start = Time::new::<second>(0.0);
+    let mut progress = 0.0;
+
+    for segment in profile.segments(max_duration)? {
+        if segment.i_bf > ElectricCurrent::new::<ampere>(0.0) {
+            let t = i_arc_in_range(c, segment.i_bf, reduced)?
+                .and_then(|i_arc| device.clearing_time(i_arc.i_arc()));
+            if let Some(t) = t {
+                let rate = 1.0 / t.get::<second>();
+                let step = rate * segment.duration.get::<second>();
+                if progress + step >= 1.0 {
+                    let dt = Time::new::<second>((1.0 - progress) / rate);
+                    return Ok(Some(start + dt));
+                }
+                progress += step;
+            }
+        }
+        start += segment.duration;
+    }
+
+    Ok(None)
+}
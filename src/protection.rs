@@ -0,0 +1,245 @@
+use anyhow::{format_err, Result};
+use uom::fmt::DisplayStyle::Abbreviation;
+use uom::si::electric_current::kiloampere;
+use uom::si::f64::{ElectricCurrent, Time};
+use uom::si::time::{millisecond, second};
+
+use crate::cubicle::Cubicle;
+use crate::e_afb::{e_afb, EAfb};
+use crate::i_arc::{i_arc, IArc};
+
+/// A protective device, or protection element, with a time-current characteristic.
+///
+/// Maps the current seen by the device to the time taken to clear the fault.
+pub trait ProtectiveDevice {
+    /// Returns the clearing time for a fault current `i`, or `None` if the device does not operate at that current.
+    fn clearing_time(&self, i: ElectricCurrent) -> Option<Time>;
+}
+
+/// Standard inverse-time overcurrent curves.
+///
+/// IEEE C37.112 curves: t = TD * (A / (M^p - 1) + B)
+///
+/// IEC 60255-151 curves: t = TMS * k / (M^a - 1)
+///
+/// Where M is the multiple of pickup current.
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub enum InverseCurve {
+    IeeeModeratelyInverse,
+    IeeeVeryInverse,
+    IeeeExtremelyInverse,
+    IecStandardInverse,
+    IecVeryInverse,
+    IecExtremelyInverse,
+    IecLongTimeInverse,
+}
+
+impl InverseCurve {
+    /// Returns the constants (A, B, p) of the curve. IEC curves have B = 0.
+    fn constants(&self) -> (f64, f64, f64) {
+        match self {
+            InverseCurve::IeeeModeratelyInverse => (0.0515, 0.1140, 0.02),
+            InverseCurve::IeeeVeryInverse => (19.61, 0.491, 2.0),
+            InverseCurve::IeeeExtremelyInverse => (28.2, 0.1217, 2.0),
+            InverseCurve::IecStandardInverse => (0.14, 0.0, 0.02),
+            InverseCurve::IecVeryInverse => (13.5, 0.0, 1.0),
+            InverseCurve::IecExtremelyInverse => (80.0, 0.0, 2.0),
+            InverseCurve::IecLongTimeInverse => (120.0, 0.0, 1.0),
+        }
+    }
+
+    /// Operating time in seconds at multiple of pickup `m`, for a time dial (or time multiplier setting) `td`.
+    fn operating_time(&self, m: f64, td: f64) -> f64 {
+        let (a, b, p) = self.constants();
+        td * (a / (m.powf(p) - 1.0) + b)
+    }
+}
+
+/// Inverse-time overcurrent element (ANSI 51).
+pub struct InverseTimeElement {
+    pub curve: InverseCurve,
+    pub pickup: ElectricCurrent,
+    /// Time dial (IEEE) or time multiplier setting (IEC).
+    pub time_dial: f64,
+}
+
+impl ProtectiveDevice for InverseTimeElement {
+    fn clearing_time(&self, i: ElectricCurrent) -> Option<Time> {
+        let m = (i / self.pickup).value;
+        if m <= 1.0 {
+            return None;
+        }
+        Some(Time::new::<second>(
+            self.curve.operating_time(m, self.time_dial),
+        ))
+    }
+}
+
+/// Definite-time overcurrent element (ANSI 50 with intentional delay, or 51 definite-time).
+pub struct DefiniteTimeElement {
+    pub pickup: ElectricCurrent,
+    pub delay: Time,
+}
+
+impl ProtectiveDevice for DefiniteTimeElement {
+    fn clearing_time(&self, i: ElectricCurrent) -> Option<Time> {
+        if i < self.pickup {
+            return None;
+        }
+        Some(self.delay)
+    }
+}
+
+/// Instantaneous overcurrent element (ANSI 50).
+pub struct InstantaneousElement {
+    pub pickup: ElectricCurrent,
+}
+
+impl ProtectiveDevice for InstantaneousElement {
+    fn clearing_time(&self, i: ElectricCurrent) -> Option<Time> {
+        if i < self.pickup {
+            return None;
+        }
+        Some(Time::new::<second>(0.0))
+    }
+}
+
+/// A circuit breaker tripped by one or more relay elements.
+///
+/// The clearing time is the fastest element's operating time plus the breaker opening time.
+pub struct RelayedBreaker {
+    pub elements: Vec<Box<dyn ProtectiveDevice>>,
+    pub opening_time: Time,
+}
+
+impl ProtectiveDevice for RelayedBreaker {
+    fn clearing_time(&self, i: ElectricCurrent) -> Option<Time> {
+        fastest(&self.elements, i).map(|t| t + self.opening_time)
+    }
+}
+
+/// Short-time band of a LV trip unit.
+pub struct ShortTime {
+    pub pickup: ElectricCurrent,
+    pub delay: Time,
+}
+
+/// Low voltage circuit breaker trip unit with long-time, short-time, and instantaneous bands.
+///
+/// Band times are total clearing times, as published on manufacturer's curves.
+pub struct LvTripUnit {
+    /// Long-time pickup (current rating) LTPU.
+    pub long_time_pickup: ElectricCurrent,
+    /// Long-time delay, i.e. the clearing time at 6 x LTPU. The long-time band follows an I²t characteristic.
+    pub long_time_delay: Time,
+    pub short_time: Option<ShortTime>,
+    pub instantaneous_pickup: Option<ElectricCurrent>,
+    /// Clearing time of the instantaneous band.
+    pub instantaneous_time: Time,
+}
+
+impl LvTripUnit {
+    pub fn new(long_time_pickup: ElectricCurrent, long_time_delay: Time) -> Self {
+        Self {
+            long_time_pickup,
+            long_time_delay,
+            short_time: None,
+            instantaneous_pickup: None,
+            instantaneous_time: Time::new::<millisecond>(50.0),
+        }
+    }
+}
+
+impl ProtectiveDevice for LvTripUnit {
+    fn clearing_time(&self, i: ElectricCurrent) -> Option<Time> {
+        let m = (i / self.long_time_pickup).value;
+        let mut t = if m > 1.0 {
+            Some(self.long_time_delay * (6.0 / m).powi(2))
+        } else {
+            None
+        };
+
+        if let Some(st) = &self.short_time {
+            if i >= st.pickup {
+                t = Some(min_time(t, st.delay));
+            }
+        }
+
+        if let Some(pickup) = self.instantaneous_pickup {
+            if i >= pickup {
+                t = Some(min_time(t, self.instantaneous_time));
+            }
+        }
+
+        t
+    }
+}
+
+/// Returns the clearing time of `device` for current `i`, limited to `max_duration`.
+///
+/// The flag is true if the device does not clear within `max_duration` (or at all), e.g. where the
+/// 2 second maximum arc duration of IEEE 1584-2018 s6.9.1 is applied.
+pub fn capped_clearing_time(
+    device: &dyn ProtectiveDevice,
+    i: ElectricCurrent,
+    max_duration: Time,
+) -> (Time, bool) {
+    arc_duration(device.clearing_time(i), Some(max_duration), i).unwrap_or((max_duration, true))
+}
+
+/// Returns the arc duration for clearing time `t` (`None` if the fault is not cleared) at arcing current `i_arc`,
+/// limited to `max_duration` if it is set, with a flag that is true if it was limited.
+///
+/// A fault that is not cleared and has no maximum duration is an error.
+pub fn arc_duration(
+    t: Option<Time>,
+    max_duration: Option<Time>,
+    i_arc: ElectricCurrent,
+) -> Result<(Time, bool)> {
+    match (t, max_duration) {
+        (Some(t), Some(max)) if t > max => Ok((max, true)),
+        (Some(t), _) => Ok((t, false)),
+        (None, Some(max)) => Ok((max, true)),
+        (None, None) => Err(format_err!(
+            "fault is not cleared for I_arc = {}",
+            i_arc.into_format_args(kiloampere, Abbreviation)
+        )),
+    }
+}
+
+fn min_time(t: Option<Time>, other: Time) -> Time {
+    match t {
+        Some(t) if t < other => t,
+        _ => other,
+    }
+}
+
+fn fastest(devices: &[Box<dyn ProtectiveDevice>], i: ElectricCurrent) -> Option<Time> {
+    devices
+        .iter()
+        .filter_map(|d| d.clearing_time(i))
+        .reduce(|a, b| if b < a { b } else { a })
+}
+
+/// Calculate the arcing current, look up the clearing time from `device`, and calculate the
+/// incident energy and Arc Flash Boundary.
+///
+/// The device is assumed to see the full arcing current `I_arc` (or the reduced arcing current if `reduced` is true).
+pub fn e_afb_with_device(
+    c: &Cubicle,
+    i_bf: ElectricCurrent,
+    reduced: bool,
+    device: &dyn ProtectiveDevice,
+) -> Result<(IArc, EAfb)> {
+    let i_arc = i_arc(c, i_bf, reduced)?;
+
+    let t_arc = device.clearing_time(i_arc.i_arc()).ok_or_else(|| {
+        format_err!(
+            "protective device does not clear I_arc = {}",
+            i_arc.i_arc().into_format_args(kiloampere, Abbreviation)
+        )
+    })?;
+
+    let e_afb = e_afb(c, i_arc.clone(), t_arc);
+    Ok((i_arc, e_afb))
+}
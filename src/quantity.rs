@@ -0,0 +1,108 @@
+use anyhow::{format_err, Result};
+use uom::si::electric_current::{ampere, kiloampere};
+use uom::si::electric_potential::{kilovolt, volt};
+use uom::si::f64::{ElectricCurrent, ElectricPotential, Frequency, Length, Time};
+use uom::si::frequency::hertz;
+use uom::si::length::{centimeter, foot, inch, meter, millimeter};
+use uom::si::time::{millisecond, second};
+
+use crate::cubicle::BoxDimensions;
+
+/// Splits a quantity such as "4.16kV" into its value and unit (in lower case), given the units accepted.
+fn split_unit<'a>(s: &str, units: &[&'a str]) -> Result<(f64, &'a str)> {
+    let lower = s.trim().to_lowercase();
+
+    // Longest units first, so that e.g. "mm" is not taken to be "m".
+    let mut sorted = units.to_vec();
+    sorted.sort_by_key(|u| std::cmp::Reverse(u.len()));
+
+    for unit in sorted {
+        if let Some(value) = lower.strip_suffix(unit) {
+            let value = value.trim();
+            if let Ok(value) = value.parse::<f64>() {
+                return Ok((value, unit));
+            }
+        }
+    }
+
+    Err(format_err!(
+        "invalid quantity {:?}: expected a number followed by one of {:?}",
+        s,
+        units
+    ))
+}
+
+/// Parses a voltage, e.g. "4.16kV" or "480V".
+pub fn parse_voltage(s: &str) -> Result<ElectricPotential> {
+    let (value, unit) = split_unit(s, &["v", "kv"])?;
+    Ok(match unit {
+        "kv" => ElectricPotential::new::<kilovolt>(value),
+        _ => ElectricPotential::new::<volt>(value),
+    })
+}
+
+/// Parses a current, e.g. "15kA" or "800A".
+pub fn parse_current(s: &str) -> Result<ElectricCurrent> {
+    let (value, unit) = split_unit(s, &["a", "ka"])?;
+    Ok(match unit {
+        "ka" => ElectricCurrent::new::<kiloampere>(value),
+        _ => ElectricCurrent::new::<ampere>(value),
+    })
+}
+
+/// Parses a length, e.g. "914.4mm", "1.5m" or "36in".
+pub fn parse_length(s: &str) -> Result<Length> {
+    let (value, unit) = split_unit(s, &["mm", "cm", "m", "in", "ft"])?;
+    Ok(match unit {
+        "mm" => Length::new::<millimeter>(value),
+        "cm" => Length::new::<centimeter>(value),
+        "in" => Length::new::<inch>(value),
+        "ft" => Length::new::<foot>(value),
+        _ => Length::new::<meter>(value),
+    })
+}
+
+/// Parses a time, e.g. "197ms" or "2s".
+pub fn parse_time(s: &str) -> Result<Time> {
+    let (value, unit) = split_unit(s, &["ms", "s"])?;
+    Ok(match unit {
+        "ms" => Time::new::<millisecond>(value),
+        _ => Time::new::<second>(value),
+    })
+}
+
+/// Parses a frequency, e.g. "60Hz".
+pub fn parse_frequency(s: &str) -> Result<Frequency> {
+    let (value, _) = split_unit(s, &["hz"])?;
+    Ok(Frequency::new::<hertz>(value))
+}
+
+/// Parses enclosure dimensions height x width x depth, e.g. "1143x762x508mm".
+///
+/// Dimensions without a unit take the unit of the last dimension.
+pub fn parse_box(s: &str) -> Result<BoxDimensions> {
+    let parts: Vec<&str> = s.split(['x', 'X', '×']).map(str::trim).collect();
+    if parts.len() != 3 {
+        return Err(format_err!(
+            "invalid enclosure dimensions {:?}: expected height x width x depth, e.g. 1143x762x508mm",
+            s
+        ));
+    }
+
+    let last = parts[2];
+    let unit = last.trim_start_matches(|c: char| c.is_ascii_digit() || c == '.' || c == '-');
+
+    let dim = |part: &str| -> Result<Length> {
+        if part.parse::<f64>().is_ok() {
+            parse_length(&format!("{}{}", part, unit))
+        } else {
+            parse_length(part)
+        }
+    };
+
+    Ok(BoxDimensions {
+        height: dim(parts[0])?,
+        width: dim(parts[1])?,
+        depth: dim(parts[2])?,
+    })
+}
@@ -0,0 +1,180 @@
+use anyhow::Result;
+use std::fmt::{Display, Formatter};
+use uom::si::f64::{ElectricCurrent, Time};
+
+use crate::approach::ApproachBoundaries;
+use crate::cubicle::Cubicle;
+use crate::e_afb::{e_afb, EAfb};
+use crate::i_arc::{i_arc, IArc};
+use crate::method::{evaluate_method, ArcFlashMethod, MethodEvaluation};
+use crate::protection::{arc_duration, ProtectiveDevice};
+
+/// Source of clearing times for the full and reduced arcing current cases, by any method.
+pub trait ClearingTimeSource {
+    /// Returns the clearing time for arcing current `i_arc` of `case`, or `None` if the fault is not cleared.
+    fn clearing_time(&self, case: ArcingCurrentCase, i_arc: ElectricCurrent) -> Option<Time>;
+}
+
+impl<D: ProtectiveDevice + ?Sized> ClearingTimeSource for D {
+    fn clearing_time(&self, _case: ArcingCurrentCase, i_arc: ElectricCurrent) -> Option<Time> {
+        ProtectiveDevice::clearing_time(self, i_arc)
+    }
+}
+
+/// Clearing times that have already been determined, e.g. by external software.
+pub struct FixedClearingTimes {
+    pub full: Time,
+    pub reduced: Time,
+}
+
+impl ClearingTimeSource for FixedClearingTimes {
+    fn clearing_time(&self, case: ArcingCurrentCase, _i_arc: ElectricCurrent) -> Option<Time> {
+        match case {
+            ArcingCurrentCase::Full => Some(self.full),
+            ArcingCurrentCase::Reduced => Some(self.reduced),
+        }
+    }
+}
+
+/// The full or reduced arcing current case.
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
+pub enum ArcingCurrentCase {
+    Full,
+    Reduced,
+}
+
+/// Arcing current and incident energy for one arcing current case.
+pub struct CaseResult {
+    pub i_arc: IArc,
+    pub e_afb: EAfb,
+    /// True if the clearing time was limited to the maximum arc duration.
+    pub capped: bool,
+}
+
+/// Results of both arcing current cases, and the case that governs (i.e. gives the highest incident energy).
+pub struct WorstCase {
+    pub full: CaseResult,
+    pub reduced: CaseResult,
+    pub governing: ArcingCurrentCase,
+    /// Shock protection approach boundaries for the cubicle.
+    pub approach: ApproachBoundaries,
+}
+
+impl WorstCase {
+    pub fn case(&self, case: ArcingCurrentCase) -> &CaseResult {
+        match case {
+            ArcingCurrentCase::Full => &self.full,
+            ArcingCurrentCase::Reduced => &self.reduced,
+        }
+    }
+
+    /// The result of the governing case.
+    pub fn governing(&self) -> &CaseResult {
+        self.case(self.governing)
+    }
+}
+
+/// A bolted fault at a cubicle.
+pub struct Scenario<'a> {
+    pub cubicle: &'a Cubicle,
+    pub i_bf: ElectricCurrent,
+    /// Maximum arc duration (e.g. 2 seconds per IEEE 1584-2018 s6.9.1). If set, clearing times are limited to this
+    /// duration and faults that are not cleared are taken to last this long. Otherwise, a fault that is not cleared
+    /// is an error.
+    pub max_arc_duration: Option<Time>,
+}
+
+impl<'a> Scenario<'a> {
+    pub fn new(cubicle: &'a Cubicle, i_bf: ElectricCurrent) -> Self {
+        Self {
+            cubicle,
+            i_bf,
+            max_arc_duration: None,
+        }
+    }
+
+    /// Calculate one arcing current case.
+    pub fn evaluate(
+        &self,
+        case: ArcingCurrentCase,
+        clearing: &dyn ClearingTimeSource,
+    ) -> Result<CaseResult> {
+        let i_arc = i_arc(self.cubicle, self.i_bf, case == ArcingCurrentCase::Reduced)?;
+
+        let (t_arc, capped) = arc_duration(
+            clearing.clearing_time(case, i_arc.i_arc()),
+            self.max_arc_duration,
+            i_arc.i_arc(),
+        )?;
+
+        let e_afb = e_afb(self.cubicle, i_arc.clone(), t_arc);
+
+        Ok(CaseResult {
+            i_arc,
+            e_afb,
+            capped,
+        })
+    }
+
+    /// Calculate both the full and reduced arcing current cases and determine which case governs.
+    pub fn evaluate_worst_case(&self, clearing: &dyn ClearingTimeSource) -> Result<WorstCase> {
+        let full = self.evaluate(ArcingCurrentCase::Full, clearing)?;
+        let reduced = self.evaluate(ArcingCurrentCase::Reduced, clearing)?;
+
+        let governing = if reduced.e_afb.e() > full.e_afb.e() {
+            ArcingCurrentCase::Reduced
+        } else {
+            ArcingCurrentCase::Full
+        };
+
+        Ok(WorstCase {
+            full,
+            reduced,
+            governing,
+            approach: self.cubicle.approach_boundaries()?,
+        })
+    }
+
+    /// Calculate every arcing current case of `method` for this fault, with the clearing times from `clearing` and
+    /// the maximum arc duration of the scenario, e.g. to study the cubicle by another method than IEEE 1584-2018.
+    pub fn evaluate_method(
+        &self,
+        method: &dyn ArcFlashMethod,
+        clearing: &dyn ClearingTimeSource,
+    ) -> Result<MethodEvaluation> {
+        evaluate_method(method, self.i_bf, clearing, self.max_arc_duration)
+    }
+}
+
+/// Calculate both the full and reduced arcing current cases for a bolted fault current `i_bf` and determine which
+/// case governs.
+pub fn evaluate_worst_case(
+    c: &Cubicle,
+    i_bf: ElectricCurrent,
+    clearing: &dyn ClearingTimeSource,
+) -> Result<WorstCase> {
+    Scenario::new(c, i_bf).evaluate_worst_case(clearing)
+}
+
+impl Display for CaseResult {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}\n{}", self.i_arc, self.e_afb)?;
+        for warning in self.e_afb.warnings() {
+            write!(f, "\nWarning: {}", warning)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for WorstCase {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (name, case) in [("Full", &self.full), ("Reduced", &self.reduced)] {
+            writeln!(f, "{}{}:", name, if case.capped { " (capped)" } else { "" })?;
+            // Indent every line of the case, not just the first.
+            for line in case.to_string().lines() {
+                writeln!(f, "    {}", line)?;
+            }
+        }
+        write!(f, "Governing case: {:?}\n{}", self.governing, self.approach)
+    }
+}
@@ -0,0 +1,42 @@
+//! Serialization of quantities as plain numbers in fixed units.
+//!
+//! Used with `#[serde(with = "...")]`. Field names carry the unit, e.g. `g_mm`, so that serialized studies are
+//! unambiguous and do not depend on the internal (SI base unit) representation of `uom`.
+
+macro_rules! unit_serde {
+    ($name:ident, $quantity:ty, $unit:ty) => {
+        pub(crate) mod $name {
+            use serde::{Deserialize, Deserializer, Serializer};
+
+            pub fn serialize<S: Serializer>(q: &$quantity, s: S) -> Result<S::Ok, S::Error> {
+                s.serialize_f64(q.get::<$unit>())
+            }
+
+            pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<$quantity, D::Error> {
+                f64::deserialize(d).map(<$quantity>::new::<$unit>)
+            }
+        }
+    };
+}
+
+unit_serde!(
+    kilovolt,
+    uom::si::f64::ElectricPotential,
+    uom::si::electric_potential::kilovolt
+);
+unit_serde!(
+    kiloampere,
+    uom::si::f64::ElectricCurrent,
+    uom::si::electric_current::kiloampere
+);
+unit_serde!(
+    millimeter,
+    uom::si::f64::Length,
+    uom::si::length::millimeter
+);
+unit_serde!(millisecond, uom::si::f64::Time, uom::si::time::millisecond);
+unit_serde!(
+    joule_per_square_centimeter,
+    uom::si::f64::RadiantExposure,
+    uom::si::radiant_exposure::joule_per_square_centimeter
+);
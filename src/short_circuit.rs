@@ -0,0 +1,321 @@
+use anyhow::{format_err, Result};
+use std::fmt::{Display, Formatter};
+use std::ops::Add;
+use uom::fmt::DisplayStyle::Abbreviation;
+use uom::si::electric_current::{ampere, kiloampere};
+use uom::si::electric_potential::{kilovolt, volt};
+use uom::si::f64::{ElectricCurrent, ElectricPotential, Length};
+use uom::si::length::kilometer;
+
+use crate::tables::NEC_TABLE_9;
+
+/// Base power (MVA) for per-unit impedances.
+pub const BASE_MVA: f64 = 100.0;
+
+/// An impedance in per unit, on a base of `BASE_MVA` and the nominal voltage where it is located.
+#[derive(PartialEq, Copy, Clone, Debug, Default)]
+pub struct PerUnitImpedance {
+    pub r: f64,
+    pub x: f64,
+}
+
+impl PerUnitImpedance {
+    /// An impedance of magnitude `z` with ratio `x_r`.
+    pub fn from_z_and_x_r(z: f64, x_r: f64) -> Self {
+        let r = z / (1.0 + x_r.powi(2)).sqrt();
+        Self { r, x: r * x_r }
+    }
+
+    pub fn z(&self) -> f64 {
+        self.r.hypot(self.x)
+    }
+
+    pub fn x_r(&self) -> f64 {
+        self.x / self.r
+    }
+}
+
+impl Add for PerUnitImpedance {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            r: self.r + other.r,
+            x: self.x + other.x,
+        }
+    }
+}
+
+/// The utility source, as the available three-phase short-circuit power at the point of supply.
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub struct UtilitySource {
+    /// Nominal voltage (phase to phase).
+    pub v: ElectricPotential,
+    /// Three-phase short-circuit power in MVA. May be infinite, for an infinite bus.
+    pub mva_sc: f64,
+    pub x_r: f64,
+}
+
+impl UtilitySource {
+    pub fn impedance(&self) -> Result<PerUnitImpedance> {
+        if !(self.mva_sc > 0.0 && self.x_r > 0.0 && self.x_r.is_finite()) {
+            return Err(format_err!(
+                "utility short-circuit MVA and X/R must be positive: {} MVA, X/R = {}",
+                self.mva_sc,
+                self.x_r
+            ));
+        }
+        Ok(PerUnitImpedance::from_z_and_x_r(
+            BASE_MVA / self.mva_sc,
+            self.x_r,
+        ))
+    }
+}
+
+/// A two-winding transformer.
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub struct Transformer {
+    pub kva: f64,
+    pub v_primary: ElectricPotential,
+    pub v_secondary: ElectricPotential,
+    /// Impedance in percent on the transformer rating.
+    pub z_percent: f64,
+    pub x_r: f64,
+}
+
+impl Transformer {
+    pub fn impedance(&self) -> Result<PerUnitImpedance> {
+        let positive = |x: f64| x > 0.0 && x.is_finite();
+        if !(positive(self.kva) && positive(self.z_percent) && positive(self.x_r)) {
+            return Err(format_err!(
+                "transformer kVA, %Z and X/R must be positive: {} kVA, {} %, X/R = {}",
+                self.kva,
+                self.z_percent,
+                self.x_r
+            ));
+        }
+        Ok(PerUnitImpedance::from_z_and_x_r(
+            self.z_percent / 100.0 * BASE_MVA / (self.kva / 1000.0),
+            self.x_r,
+        ))
+    }
+
+    /// Impedance on the voltage base `v` of the bus the primary is connected to, i.e. corrected by
+    /// `(v_primary / v)²` for a tap that does not match the system voltage.
+    pub fn impedance_on_base(&self, v: ElectricPotential) -> Result<PerUnitImpedance> {
+        let z = self.impedance()?;
+        let k = (self.v_primary / v).value.powi(2);
+        Ok(PerUnitImpedance {
+            r: z.r * k,
+            x: z.x * k,
+        })
+    }
+
+    /// The voltage base on the secondary for a voltage base `v` on the primary, by the turns ratio.
+    pub fn secondary_base(&self, v: ElectricPotential) -> ElectricPotential {
+        v * (self.v_secondary / self.v_primary)
+    }
+}
+
+/// Conductor size, AWG or kcmil.
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
+pub enum ConductorSize {
+    Awg14,
+    Awg12,
+    Awg10,
+    Awg8,
+    Awg6,
+    Awg4,
+    Awg3,
+    Awg2,
+    Awg1,
+    Awg1_0,
+    Awg2_0,
+    Awg3_0,
+    Awg4_0,
+    Kcmil250,
+    Kcmil300,
+    Kcmil350,
+    Kcmil400,
+    Kcmil500,
+    Kcmil600,
+    Kcmil750,
+    Kcmil1000,
+}
+
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum ConductorMaterial {
+    Copper,
+    Aluminum,
+}
+
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum Conduit {
+    /// PVC or other non-metallic conduit.
+    Pvc,
+    Aluminum,
+    Steel,
+}
+
+/// A cable or busway.
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub struct Feeder {
+    pub length: Length,
+    /// Resistance per conductor, in ohms per km (see `from_conductor`, or from manufacturer's data).
+    pub r_ohm_per_km: f64,
+    /// Reactance per conductor, in ohms per km.
+    pub x_ohm_per_km: f64,
+    /// Number of conductors in parallel per phase.
+    pub sets: u32,
+}
+
+impl Feeder {
+    /// A feeder of `sets` parallel conductors of `size` and `material` in `conduit`, with the resistance and
+    /// reactance of NEC Chapter 9, Table 9 (600 V cables at 75 °C, 60 Hz).
+    pub fn from_conductor(
+        length: Length,
+        size: ConductorSize,
+        material: ConductorMaterial,
+        conduit: Conduit,
+        sets: u32,
+    ) -> Result<Self> {
+        let row = &NEC_TABLE_9[&size];
+        let r = match material {
+            ConductorMaterial::Copper => row.r_copper,
+            ConductorMaterial::Aluminum => row.r_aluminum.ok_or_else(|| {
+                format_err!("NEC Table 9 has no aluminum conductor of size {:?}", size)
+            })?,
+        };
+        let (r, x) = match conduit {
+            Conduit::Pvc => (r[0], row.x[0]),
+            Conduit::Aluminum => (r[1], row.x[0]),
+            Conduit::Steel => (r[2], row.x[1]),
+        };
+        // Table 9 is in ohms per 1000 ft.
+        let per_km = 1000.0 / 304.8;
+
+        Ok(Self {
+            length,
+            r_ohm_per_km: r * per_km,
+            x_ohm_per_km: x * per_km,
+            sets,
+        })
+    }
+
+    /// Impedance at nominal voltage `v`.
+    pub fn impedance(&self, v: ElectricPotential) -> Result<PerUnitImpedance> {
+        let km = self.length.get::<kilometer>();
+        if !(km >= 0.0
+            && km.is_finite()
+            && self.r_ohm_per_km >= 0.0
+            && self.x_ohm_per_km >= 0.0
+            && self.sets > 0)
+        {
+            return Err(format_err!(
+                "feeder length and impedance must not be negative, and there must be at least one set"
+            ));
+        }
+        let z_base = v.get::<kilovolt>().powi(2) / BASE_MVA;
+        let sets = self.sets as f64;
+
+        Ok(PerUnitImpedance {
+            r: self.r_ohm_per_km * km / sets / z_base,
+            x: self.x_ohm_per_km * km / sets / z_base,
+        })
+    }
+}
+
+/// An element in series between the source and the fault.
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub enum SeriesElement {
+    Transformer(Transformer),
+    Feeder(Feeder),
+}
+
+/// A three-phase bolted fault at a bus.
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub struct ShortCircuit {
+    /// Voltage base of the bus: the source voltage carried through the transformer turns ratios.
+    pub v: ElectricPotential,
+    /// Impedance from the source to the bus, in per unit on `BASE_MVA`.
+    pub z: PerUnitImpedance,
+    pub i_bf: ElectricCurrent,
+}
+
+impl ShortCircuit {
+    /// The three-phase bolted fault current at a bus with nominal voltage `v`, for a source impedance `z`.
+    pub fn new(v: ElectricPotential, z: PerUnitImpedance) -> Result<Self> {
+        if !(v.get::<volt>() > 0.0 && z.z() > 0.0) {
+            return Err(format_err!(
+                "voltage and impedance to the fault must be positive"
+            ));
+        }
+        let i_base = BASE_MVA * 1e6 / (3.0_f64.sqrt() * v.get::<volt>());
+
+        Ok(Self {
+            v,
+            z,
+            i_bf: ElectricCurrent::new::<ampere>(i_base / z.z()),
+        })
+    }
+
+    pub fn x_r(&self) -> f64 {
+        self.z.x_r()
+    }
+}
+
+/// A radial feed from a utility source through transformers and feeders, to a fault at the end.
+pub struct RadialFeed {
+    pub source: UtilitySource,
+    /// In order from the source.
+    pub elements: Vec<SeriesElement>,
+}
+
+impl RadialFeed {
+    /// Calculate the three-phase bolted fault current at the end of the feed.
+    ///
+    /// The primary voltage of each transformer must be within 10 % of the voltage base where it is connected. A
+    /// transformer tapped off the system voltage has its impedance converted to the system base, and the voltage
+    /// base on its secondary follows the turns ratio (see `Transformer::impedance_on_base`).
+    pub fn fault(&self) -> Result<ShortCircuit> {
+        let mut v = self.source.v;
+        let mut z = self.source.impedance()?;
+
+        for element in &self.elements {
+            match element {
+                SeriesElement::Transformer(tx) => {
+                    let ratio = (tx.v_primary / v).value;
+                    if !(0.9..=1.1).contains(&ratio) {
+                        return Err(format_err!(
+                            "transformer primary voltage {} does not match the nominal voltage {}",
+                            tx.v_primary.into_format_args(kilovolt, Abbreviation),
+                            v.into_format_args(kilovolt, Abbreviation)
+                        ));
+                    }
+                    z = z + tx.impedance_on_base(v)?;
+                    v = tx.secondary_base(v);
+                }
+                SeriesElement::Feeder(feeder) => {
+                    z = z + feeder.impedance(v)?;
+                }
+            }
+        }
+
+        ShortCircuit::new(v, z)
+    }
+}
+
+impl Display for ShortCircuit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "V = {}, Z = {:.5} + j{:.5} pu ({} MVA base), X/R = {:.2}, I_bf = {}",
+            self.v.into_format_args(kilovolt, Abbreviation),
+            self.z.r,
+            self.z.x,
+            BASE_MVA,
+            self.x_r(),
+            self.i_bf.into_format_args(kiloampere, Abbreviation)
+        )
+    }
+}
@@ -1,11 +1,13 @@
+mod nec_table9;
 mod table1;
 mod table2;
 mod table3_4_5;
 mod table7;
-// mod table8_10;
+mod table8_10;
 
+pub use nec_table9::*;
 pub use table1::*;
 pub use table2::*;
 pub use table3_4_5::*;
 pub use table7::*;
-// pub use table8_10::*;
+pub use table8_10::*;
@@ -0,0 +1,55 @@
+use crate::short_circuit::ConductorSize;
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+
+lazy_static! {
+    /// NFPA 70 (NEC) Chapter 9, Table 9: AC resistance and reactance for 600 V cables, three-phase, 60 Hz,
+    /// 75 °C, three single conductors in conduit, in ohms to neutral per 1000 ft.
+    pub static ref NEC_TABLE_9: HashMap<ConductorSize, NecTable9Row> = {
+        use ConductorSize::*;
+        HashMap::from([
+            (Awg14, NecTable9Row::new([0.058, 0.073], [3.1, 3.1, 3.1], None)),
+            (Awg12, NecTable9Row::new([0.054, 0.068], [2.0, 2.0, 2.0], Some([3.2, 3.2, 3.2]))),
+            (Awg10, NecTable9Row::new([0.050, 0.063], [1.2, 1.2, 1.2], Some([2.0, 2.0, 2.0]))),
+            (Awg8, NecTable9Row::new([0.052, 0.065], [0.78, 0.78, 0.78], Some([1.3, 1.3, 1.3]))),
+            (Awg6, NecTable9Row::new([0.051, 0.064], [0.49, 0.49, 0.49], Some([0.81, 0.81, 0.81]))),
+            (Awg4, NecTable9Row::new([0.048, 0.060], [0.31, 0.31, 0.31], Some([0.51, 0.51, 0.51]))),
+            (Awg3, NecTable9Row::new([0.047, 0.059], [0.25, 0.25, 0.25], Some([0.40, 0.41, 0.40]))),
+            (Awg2, NecTable9Row::new([0.045, 0.057], [0.19, 0.20, 0.20], Some([0.32, 0.32, 0.32]))),
+            (Awg1, NecTable9Row::new([0.046, 0.057], [0.15, 0.16, 0.16], Some([0.25, 0.26, 0.25]))),
+            (Awg1_0, NecTable9Row::new([0.044, 0.055], [0.12, 0.13, 0.12], Some([0.20, 0.21, 0.20]))),
+            (Awg2_0, NecTable9Row::new([0.043, 0.054], [0.10, 0.10, 0.10], Some([0.16, 0.16, 0.16]))),
+            (Awg3_0, NecTable9Row::new([0.042, 0.052], [0.077, 0.082, 0.079], Some([0.13, 0.13, 0.13]))),
+            (Awg4_0, NecTable9Row::new([0.041, 0.051], [0.062, 0.067, 0.063], Some([0.10, 0.11, 0.10]))),
+            (Kcmil250, NecTable9Row::new([0.041, 0.052], [0.052, 0.057, 0.054], Some([0.085, 0.090, 0.086]))),
+            (Kcmil300, NecTable9Row::new([0.041, 0.051], [0.044, 0.049, 0.045], Some([0.071, 0.076, 0.072]))),
+            (Kcmil350, NecTable9Row::new([0.040, 0.050], [0.038, 0.043, 0.039], Some([0.061, 0.066, 0.063]))),
+            (Kcmil400, NecTable9Row::new([0.040, 0.049], [0.033, 0.038, 0.035], Some([0.054, 0.059, 0.055]))),
+            (Kcmil500, NecTable9Row::new([0.039, 0.048], [0.027, 0.032, 0.029], Some([0.043, 0.048, 0.045]))),
+            (Kcmil600, NecTable9Row::new([0.039, 0.048], [0.023, 0.028, 0.025], Some([0.036, 0.041, 0.038]))),
+            (Kcmil750, NecTable9Row::new([0.038, 0.048], [0.019, 0.024, 0.021], Some([0.029, 0.034, 0.031]))),
+            (Kcmil1000, NecTable9Row::new([0.037, 0.046], [0.015, 0.019, 0.018], Some([0.023, 0.027, 0.025]))),
+        ])
+    };
+}
+
+/// A row of NEC Table 9, in ohms per 1000 ft.
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub struct NecTable9Row {
+    /// Reactance in PVC or aluminum conduit, and in steel conduit.
+    pub x: [f64; 2],
+    /// Copper resistance in PVC, aluminum and steel conduit.
+    pub r_copper: [f64; 3],
+    /// Aluminum resistance in PVC, aluminum and steel conduit, if the size is listed.
+    pub r_aluminum: Option<[f64; 3]>,
+}
+
+impl NecTable9Row {
+    pub fn new(x: [f64; 2], r_copper: [f64; 3], r_aluminum: Option<[f64; 3]>) -> Self {
+        Self {
+            x,
+            r_copper,
+            r_aluminum,
+        }
+    }
+}
@@ -114,6 +114,8 @@ lazy_static! {
     };
 }
 
+#[derive(PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Table1Row {
     // ec: String,
     // v_oc: f64,
@@ -130,6 +132,7 @@ pub struct Table1Row {
 }
 
 impl Table1Row {
+    #[allow(clippy::too_many_arguments)]
     fn new(
         k1: f64,
         k2: f64,
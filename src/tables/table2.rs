@@ -63,6 +63,8 @@ lazy_static! {
     };
 }
 
+#[derive(PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Table2Row {
     // ec: String,
     pub k1: f64,
@@ -177,6 +177,8 @@ lazy_static! {
     };
 }
 
+#[derive(PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Table3_4_5Row {
     // v600: String,
     pub k1: f64,
@@ -195,6 +197,7 @@ pub struct Table3_4_5Row {
 }
 
 impl Table3_4_5Row {
+    #[allow(clippy::too_many_arguments)]
     fn new(
         k1: f64,
         k2: f64,
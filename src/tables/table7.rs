@@ -33,6 +33,8 @@ lazy_static! {
     };
 }
 
+#[derive(PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Table7Row {
     // box_type: String,
     // ec: String,
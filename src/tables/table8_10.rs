@@ -1,59 +1,33 @@
-use lazy_static::lazy_static;
-use std::collections::HashMap;
+use crate::common::EquipmentClass;
 
-lazy_static! {
-    pub static ref TABLE_8_10: HashMap<&'static str, TypicalParams> = {
-        HashMap::from([
-            (
-                "15kV Switchgear",
-                TypicalParams::new(152.0, 1143.0, 762.0, 762.0, 914.4),
-            ),
-            (
-                "15kV MCC, 152",
-                TypicalParams::new(914.4, 914.4, 914.4, 914.4, 914.4),
-            ),
-            (
-                "5kV Switchgear",
-                TypicalParams::new(104.0, 914.4, 914.4, 914.4, 914.4),
-            ),
-            (
-                "5kV Switchgear (2)",
-                TypicalParams::new(104.0, 1143.0, 762.0, 762.0, 914.4),
-            ),
-            (
-                "5kV MCC",
-                TypicalParams::new(104.0, 660.4, 660.4, 660.4, 914.4),
-            ),
-            (
-                "LV Switchgear",
-                TypicalParams::new(32.0, 508.0, 508.0, 508.0, 609.6),
-            ),
-            (
-                "LV MCC (Shallow)",
-                TypicalParams::new(25.0, 355.6, 304.8, 100.0, 457.2),
-            ),
-            (
-                "LV Panelboard (Shallow)",
-                TypicalParams::new(25.0, 355.6, 304.8, 100.0, 457.2),
-            ),
-            (
-                "LV MCC",
-                TypicalParams::new(25.0, 355.6, 304.8, 250.0, 457.2),
-            ),
-            (
-                "LV Panelboard",
-                TypicalParams::new(25.0, 355.6, 304.8, 250.0, 457.2),
-            ),
-            (
-                "Cable Junction Box (Shallow)",
-                TypicalParams::new(13.0, 355.6, 304.8, 100.0, 457.2),
-            ),
-            (
-                "Cable Junction Box",
-                TypicalParams::new(13.0, 355.6, 304.8, 250.0, 457.2),
-            ),
-        ])
-    };
+impl EquipmentClass {
+    /// Typical busbar gap, enclosure size, and working distance of the class, from Table 8 and Table 10.
+    pub fn typical_params(&self) -> TypicalParams {
+        match self {
+            EquipmentClass::Switchgear15kV => {
+                TypicalParams::new(152.0, 1143.0, 762.0, 762.0, 914.4)
+            }
+            EquipmentClass::Mcc15kV => TypicalParams::new(152.0, 914.4, 914.4, 914.4, 914.4),
+            EquipmentClass::Switchgear5kV => TypicalParams::new(104.0, 914.4, 914.4, 914.4, 914.4),
+            EquipmentClass::Switchgear5kVTall => {
+                TypicalParams::new(104.0, 1143.0, 762.0, 762.0, 914.4)
+            }
+            EquipmentClass::Mcc5kV => TypicalParams::new(104.0, 660.4, 660.4, 660.4, 914.4),
+            EquipmentClass::LvSwitchgear => TypicalParams::new(32.0, 508.0, 508.0, 508.0, 609.6),
+            EquipmentClass::LvMccShallow => TypicalParams::new(25.0, 355.6, 304.8, 100.0, 457.2),
+            EquipmentClass::LvPanelboardShallow => {
+                TypicalParams::new(25.0, 355.6, 304.8, 100.0, 457.2)
+            }
+            EquipmentClass::LvMcc => TypicalParams::new(25.0, 355.6, 304.8, 250.0, 457.2),
+            EquipmentClass::LvPanelboard => TypicalParams::new(25.0, 355.6, 304.8, 250.0, 457.2),
+            EquipmentClass::CableJunctionBoxShallow => {
+                TypicalParams::new(13.0, 355.6, 304.8, 100.0, 457.2)
+            }
+            EquipmentClass::CableJunctionBox => {
+                TypicalParams::new(13.0, 355.6, 304.8, 250.0, 457.2)
+            }
+        }
+    }
 }
 
 /// This is a combination of table 8 and table 10.
@@ -65,6 +39,7 @@ lazy_static! {
 /// LV equipment with "shallow" depth <= 8 inches - set to 100 mm.
 /// LV equipment with "deep" depth > 8 inches - set to 250 mm.
 /// Precise depths don't matter, only whether the enclosure is "shallow" or "deep".
+#[derive(PartialEq, Copy, Clone, Debug)]
 pub struct TypicalParams {
     /// Typical busbar gap in mm.
     pub g: f64,
@@ -0,0 +1,207 @@
+use anyhow::{format_err, Result};
+use std::io::Read;
+use uom::si::electric_current::ampere;
+use uom::si::f64::{ElectricCurrent, Time};
+use uom::si::time::second;
+
+use crate::protection::ProtectiveDevice;
+
+/// A point on a tabulated time-current curve.
+#[derive(Copy, Clone, Debug)]
+pub struct TccPoint {
+    pub current: ElectricCurrent,
+    /// Total clearing time (upper edge of the tolerance band).
+    pub time: Time,
+    /// Minimum time (e.g. minimum melting time of a fuse, or lower edge of a breaker's tolerance band).
+    pub min_time: Option<Time>,
+}
+
+/// A time-current curve for a fuse or circuit breaker, as published by the manufacturer as a list of points.
+///
+/// Times must not increase with current. Clearing times between points are interpolated in log-log space. Above the highest current the curve is
+/// taken to be flat. Below the lowest current the device is taken not to clear.
+pub struct TabulatedCurve {
+    points: Vec<TccPoint>,
+}
+
+impl TabulatedCurve {
+    pub fn new(mut points: Vec<TccPoint>) -> Result<Self> {
+        if points.len() < 2 {
+            return Err(format_err!(
+                "time-current curve must have at least 2 points: {}",
+                points.len()
+            ));
+        }
+
+        points.sort_by(|a, b| {
+            a.current
+                .get::<ampere>()
+                .total_cmp(&b.current.get::<ampere>())
+        });
+
+        for p in &points {
+            let min_time = p.min_time.unwrap_or(p.time);
+            if !(p.current.get::<ampere>() > 0.0
+                && p.current.is_finite()
+                && p.time.get::<second>() > 0.0
+                && p.time.is_finite()
+                && min_time.get::<second>() > 0.0
+                && min_time.is_finite())
+            {
+                return Err(format_err!(
+                    "time-current curve currents and times must be positive and finite: {} A, {} s",
+                    p.current.get::<ampere>(),
+                    p.time.get::<second>()
+                ));
+            }
+            if min_time > p.time {
+                return Err(format_err!(
+                    "minimum time must not exceed total clearing time at {} A",
+                    p.current.get::<ampere>()
+                ));
+            }
+        }
+        for w in points.windows(2) {
+            if w[0].current == w[1].current {
+                return Err(format_err!(
+                    "time-current curve has duplicate current {} A",
+                    w[0].current.get::<ampere>()
+                ));
+            }
+            // Inverse time: a higher current never takes longer to clear.
+            let min_time_increases = match (w[0].min_time, w[1].min_time) {
+                (Some(t0), Some(t1)) => t1 > t0,
+                _ => false,
+            };
+            if w[1].time > w[0].time || min_time_increases {
+                return Err(format_err!(
+                    "time-current curve times must not increase with current: {} A to {} A",
+                    w[0].current.get::<ampere>(),
+                    w[1].current.get::<ampere>()
+                ));
+            }
+        }
+
+        Ok(Self { points })
+    }
+
+    /// Reads a curve from CSV.
+    ///
+    /// The first row is a header and is ignored. Columns are current (A), total clearing time (s) and,
+    /// optionally, minimum time (s). Lines starting with `#` are comments.
+    pub fn from_csv<R: Read>(rdr: R) -> Result<Self> {
+        let mut rdr = csv::ReaderBuilder::new()
+            .trim(csv::Trim::All)
+            .comment(Some(b'#'))
+            .flexible(true)
+            .from_reader(rdr);
+
+        let mut points = Vec::new();
+        for (i, record) in rdr.records().enumerate() {
+            let record = record?;
+            let field = |col: usize| -> Result<Option<f64>> {
+                match record.get(col) {
+                    None | Some("") => Ok(None),
+                    Some(s) => s.parse::<f64>().map(Some).map_err(|_| {
+                        format_err!("row {}, column {}: invalid number {:?}", i + 1, col + 1, s)
+                    }),
+                }
+            };
+
+            let current = field(0)?.ok_or_else(|| format_err!("row {}: missing current", i + 1))?;
+            let time = field(1)?.ok_or_else(|| format_err!("row {}: missing time", i + 1))?;
+            let min_time = field(2)?;
+
+            points.push(TccPoint {
+                current: ElectricCurrent::new::<ampere>(current),
+                time: Time::new::<second>(time),
+                min_time: min_time.map(Time::new::<second>),
+            });
+        }
+
+        Self::new(points)
+    }
+
+    /// Reads a curve from JSON of the form:
+    ///
+    /// `{"points": [{"current": 100.0, "time": 300.0, "min_time": 150.0}, ...]}`
+    ///
+    /// With currents in amperes and times in seconds. `min_time` is optional.
+    #[cfg(feature = "serde")]
+    pub fn from_json<R: Read>(rdr: R) -> Result<Self> {
+        #[derive(serde::Deserialize)]
+        struct Point {
+            current: f64,
+            time: f64,
+            min_time: Option<f64>,
+        }
+        #[derive(serde::Deserialize)]
+        struct Curve {
+            points: Vec<Point>,
+        }
+
+        let curve: Curve = serde_json::from_reader(rdr)?;
+
+        Self::new(
+            curve
+                .points
+                .into_iter()
+                .map(|p| TccPoint {
+                    current: ElectricCurrent::new::<ampere>(p.current),
+                    time: Time::new::<second>(p.time),
+                    min_time: p.min_time.map(Time::new::<second>),
+                })
+                .collect(),
+        )
+    }
+
+    pub fn points(&self) -> &[TccPoint] {
+        &self.points
+    }
+
+    /// The lowest current at which the device clears.
+    pub fn min_current(&self) -> ElectricCurrent {
+        self.points[0].current
+    }
+
+    /// Returns the minimum time (lower edge of the tolerance band) for current `i`, or `None` if the current is
+    /// below the curve. Where the curve has no minimum times the total clearing time is used.
+    pub fn min_clearing_time(&self, i: ElectricCurrent) -> Option<Time> {
+        self.interpolate(i, |p| p.min_time.unwrap_or(p.time))
+    }
+
+    fn interpolate(&self, i: ElectricCurrent, time: impl Fn(&TccPoint) -> Time) -> Option<Time> {
+        let first = &self.points[0];
+        let last = &self.points[self.points.len() - 1];
+
+        if i < first.current {
+            return None;
+        }
+        if i >= last.current {
+            return Some(time(last));
+        }
+
+        let k = self.points.partition_point(|p| p.current <= i);
+        let (p0, p1) = (&self.points[k - 1], &self.points[k]);
+
+        let x = i.get::<ampere>().log10();
+        let (x0, x1) = (
+            p0.current.get::<ampere>().log10(),
+            p1.current.get::<ampere>().log10(),
+        );
+        let (y0, y1) = (
+            time(p0).get::<second>().log10(),
+            time(p1).get::<second>().log10(),
+        );
+
+        let y = y0 + (x - x0) * (y1 - y0) / (x1 - x0);
+
+        Some(Time::new::<second>(10.0_f64.powf(y)))
+    }
+}
+
+impl ProtectiveDevice for TabulatedCurve {
+    fn clearing_time(&self, i: ElectricCurrent) -> Option<Time> {
+        self.interpolate(i, |p| p.time)
+    }
+}
@@ -1,3 +1,61 @@
+mod test_approach;
+mod test_batch;
+mod test_bounds;
+mod test_builder;
+mod test_coefficients;
 mod test_d1;
 mod test_d2;
+mod test_dc;
+mod test_distance;
+mod test_electrode;
+mod test_error;
+mod test_generator;
+mod test_ieee2002;
+mod test_label;
+mod test_lee;
+mod test_method;
+mod test_motor;
 mod test_multistep;
+mod test_network;
+mod test_ppe;
+mod test_profile;
+mod test_protection;
+mod test_quantity;
+mod test_scenario;
+#[cfg(feature = "serde")]
+mod test_serde;
+mod test_short_circuit;
+mod test_tcc;
+mod test_warning;
+
+use crate::{Cubicle, ElectrodeConfiguration};
+use uom::si::electric_potential::{kilovolt, ElectricPotential};
+use uom::si::length::{millimeter, Length};
+
+/// The HV example of Annex D.1: 4.16 kV VCB, 104 mm gap, 914.4 mm working distance, 1143 × 762 × 508 mm enclosure.
+fn d1_cubicle() -> Cubicle {
+    Cubicle::new(
+        ElectricPotential::new::<kilovolt>(4.16),
+        ElectrodeConfiguration::VCB,
+        Length::new::<millimeter>(104.0),
+        Length::new::<millimeter>(914.4),
+        Length::new::<millimeter>(1143.0),
+        Length::new::<millimeter>(762.0),
+        Length::new::<millimeter>(508.0),
+    )
+    .unwrap()
+}
+
+/// The LV example of Annex D.2: 480 V VCB, 32 mm gap, 609.6 mm working distance, 610 × 610 × 254 mm enclosure.
+fn d2_cubicle() -> Cubicle {
+    Cubicle::new(
+        ElectricPotential::new::<kilovolt>(0.48),
+        ElectrodeConfiguration::VCB,
+        Length::new::<millimeter>(32.0),
+        Length::new::<millimeter>(609.6),
+        Length::new::<millimeter>(610.0),
+        Length::new::<millimeter>(610.0),
+        Length::new::<millimeter>(254.0),
+    )
+    .unwrap()
+}
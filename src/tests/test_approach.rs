@@ -0,0 +1,34 @@
+use crate::{approach_boundaries, ApproachDistance};
+use uom::si::electric_potential::{kilovolt, volt, ElectricPotential};
+use uom::si::length::{meter, Length};
+
+#[test]
+fn test_approach_boundaries() {
+    let m = |d: f64| ApproachDistance::Distance(Length::new::<meter>(d));
+
+    let b = approach_boundaries(ElectricPotential::new::<volt>(24.0)).unwrap();
+    assert_eq!(b.restricted, ApproachDistance::NotSpecified);
+
+    let b = approach_boundaries(ElectricPotential::new::<volt>(120.0)).unwrap();
+    assert_eq!(b.limited_fixed, m(1.0));
+    assert_eq!(b.restricted, ApproachDistance::AvoidContact);
+
+    let b = approach_boundaries(ElectricPotential::new::<volt>(480.0)).unwrap();
+    assert_eq!(b.limited_movable, m(3.0));
+    assert_eq!(b.limited_fixed, m(1.0));
+    assert_eq!(b.restricted, m(0.3));
+
+    let b = approach_boundaries(ElectricPotential::new::<kilovolt>(13.8)).unwrap();
+    assert_eq!(b.limited_fixed, m(1.5));
+    assert_eq!(b.restricted, m(0.7));
+
+    let b = approach_boundaries(ElectricPotential::new::<kilovolt>(33.0)).unwrap();
+    assert_eq!(b.limited_fixed, m(1.8));
+    assert_eq!(b.restricted, m(0.8));
+
+    // Between the ranges of the table
+    let b = approach_boundaries(ElectricPotential::new::<kilovolt>(132.0)).unwrap();
+    assert_eq!(b.limited_movable, m(3.4));
+
+    assert!(approach_boundaries(ElectricPotential::new::<kilovolt>(1000.0)).is_err());
+}
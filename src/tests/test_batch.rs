@@ -0,0 +1,123 @@
+use crate::{read_batch, run_batch, run_batch_with, write_results, ArcingCurrentCase, Lee};
+use float_cmp::assert_approx_eq;
+use uom::si::radiant_exposure::joule_per_square_centimeter;
+
+const STUDY: &str = "\
+name,voltage,ec,gap,distance,box,ibf,t,t_reduced
+# Annex D.1
+SWGR-1,4.16kV,VCB,104,914.4,1143x762x508,15,197,223
+# gap too small for HV
+SWGR-2,4.16kV,VCB,10,914.4,1143x762x508,15,197,223
+MCC-1,480V,VCB,32,609.6,610x610x254mm,45kA,61.3ms,319ms
+,480V,XYZ,32,609.6,610x610x254,45,61.3,319
+";
+
+/// Test a batch study with the Annex D.1 and D.2 examples and rows that fail.
+#[test]
+fn test_batch() {
+    let rows = read_batch(STUDY.as_bytes()).unwrap();
+    assert_eq!(rows.len(), 4);
+
+    let results = run_batch(rows);
+    assert_eq!(results[0].name, "SWGR-1");
+    assert_eq!(results[3].name, "row 4");
+
+    let (_, evaluation) = results[0].result.as_ref().unwrap();
+    // D.62
+    assert_approx_eq!(
+        f64,
+        evaluation
+            .governing()
+            .result
+            .e()
+            .get::<joule_per_square_centimeter>(),
+        13.343,
+        epsilon = 1e-3
+    );
+    assert_eq!(evaluation.governing().case, ArcingCurrentCase::Reduced);
+
+    assert!(results[1].result.is_err());
+
+    let (_, evaluation) = results[2].result.as_ref().unwrap();
+    // D.103
+    assert_approx_eq!(
+        f64,
+        evaluation
+            .governing()
+            .result
+            .e()
+            .get::<joule_per_square_centimeter>(),
+        53.156,
+        epsilon = 1e-3
+    );
+
+    assert!(results[3].result.is_err());
+
+    let mut out = Vec::new();
+    write_results(&mut out, &results).unwrap();
+    let out = String::from_utf8(out).unwrap();
+    let lines: Vec<&str> = out.lines().collect();
+    assert_eq!(lines.len(), 5);
+    assert!(lines[0].starts_with("name,i_arc_full_ka,"));
+    assert!(lines[0].ends_with(",cf,var_cf,warnings,error"));
+    assert!(lines[1].starts_with("SWGR-1,"));
+    assert!(lines[1].contains(",Reduced,"));
+    assert!(lines[1].ends_with(",,"));
+    assert!(lines[2].contains("busbar gap G must be >= 19.05 mm"));
+
+    // Rows that failed have the same columns as the header, with the error in the last.
+    let mut rdr = csv::Reader::from_reader(out.as_bytes());
+    let columns = rdr.headers().unwrap().len();
+    for record in rdr.records() {
+        assert_eq!(record.unwrap().len(), columns);
+    }
+}
+
+/// A batch study by a method with no reduced arcing current case leaves the reduced columns empty.
+#[test]
+fn test_batch_with_method() {
+    let rows = read_batch(STUDY.as_bytes()).unwrap();
+    let results = run_batch_with(rows, &|c| {
+        Box::new(Lee {
+            v_oc: c.v_oc,
+            d: c.d,
+        })
+    });
+
+    let (_, evaluation) = results[0].result.as_ref().unwrap();
+    assert_eq!(evaluation.method, "Lee");
+    assert!(evaluation.case(ArcingCurrentCase::Reduced).is_none());
+    assert_eq!(evaluation.governing().case, ArcingCurrentCase::Full);
+
+    let mut out = Vec::new();
+    write_results(&mut out, &results).unwrap();
+    let out = String::from_utf8(out).unwrap();
+    let fields: Vec<&str> = out.lines().nth(1).unwrap().split(',').collect();
+    assert_eq!(fields[0], "SWGR-1");
+    // Full arcing current (the bolted fault current, for Lee) and an empty reduced arcing current.
+    assert_eq!(fields[1], "15");
+    assert_eq!(fields[2], "");
+    assert_eq!(fields[9], "Full");
+}
+
+/// The frequency column is checked with the other cubicle parameters.
+#[test]
+fn test_batch_frequency() {
+    let study = "\
+voltage,ec,gap,distance,box,ibf,t,frequency
+4.16kV,VCB,104,914.4,1143x762x508,15,197,50
+4.16kV,VCB,104,914.4,1143x762x508,15,197,60Hz
+4.16kV,VCB,104,914.4,1143x762x508,15,197,400
+4.16kV,VCB,104,914.4,1143x762x508,15,197,
+";
+    let results = run_batch(read_batch(study.as_bytes()).unwrap());
+    assert!(results[0].result.is_ok());
+    assert!(results[1].result.is_ok());
+    let err = results[2].result.as_ref().err().unwrap();
+    assert_eq!(
+        err.to_string(),
+        "frequency 400 Hz is outside the range of the model (50 Hz or 60 Hz)"
+    );
+    let (cubicle, _) = results[3].result.as_ref().unwrap();
+    assert_eq!(cubicle.frequency(), None);
+}
@@ -0,0 +1,396 @@
+use crate::{
+    dc_arc_flash, e_afb, i_arc, multistep_profile, multistep_total, CoefficientSet, Cubicle,
+    DcArcModel, DcEnclosure, DcSource, ElectrodeConfiguration, EnclosureType, FaultCurrentProfile,
+    IArc, NominalVoltage, ProfileSegment,
+};
+use proptest::prelude::*;
+use std::sync::Arc;
+use uom::si::electric_current::{ampere, kiloampere, ElectricCurrent};
+use uom::si::electric_potential::{kilovolt, volt, ElectricPotential};
+use uom::si::f64::{RadiantExposure, Time};
+use uom::si::length::{millimeter, Length};
+use uom::si::radiant_exposure::joule_per_square_centimeter;
+use uom::si::time::millisecond;
+
+fn electrode_configuration() -> impl Strategy<Value = ElectrodeConfiguration> {
+    prop_oneof![
+        Just(ElectrodeConfiguration::VCB),
+        Just(ElectrodeConfiguration::VCBB),
+        Just(ElectrodeConfiguration::HCB),
+        Just(ElectrodeConfiguration::VOA),
+        Just(ElectrodeConfiguration::HOA),
+    ]
+}
+
+/// Any finite value, weighted towards the range `min` to `max`.
+fn value(min: f64, max: f64) -> impl Strategy<Value = f64> {
+    prop_oneof![
+        4 => min..max,
+        1 => -1e3..1e5,
+        1 => any::<f64>().prop_filter("finite", |v| v.is_finite()),
+        1 => Just(0.0),
+    ]
+}
+
+/// A cubicle within the range of the model, or `None` for inputs outside it.
+fn cubicle(v_oc: f64, ec: ElectrodeConfiguration, g: f64, d: f64) -> Option<Cubicle> {
+    Cubicle::new(
+        ElectricPotential::new::<kilovolt>(v_oc),
+        ec,
+        Length::new::<millimeter>(g),
+        Length::new::<millimeter>(d),
+        Length::new::<millimeter>(1143.0),
+        Length::new::<millimeter>(762.0),
+        Length::new::<millimeter>(508.0),
+    )
+    .ok()
+}
+
+/// Any value, including non-finite ones.
+fn any_value() -> impl Strategy<Value = f64> {
+    prop_oneof![
+        4 => -10.0..10.0,
+        1 => any::<f64>(),
+        1 => Just(f64::NAN),
+        1 => Just(f64::INFINITY),
+    ]
+}
+
+/// Sets coefficient `i` (of whichever row is selected by `table`, `v_oc`, `ec` and `enclosure_type`) to `k`, or
+/// removes the row if `k` is `None`.
+fn modify(
+    set: &mut CoefficientSet,
+    table: usize,
+    v_oc: NominalVoltage,
+    ec: ElectrodeConfiguration,
+    enclosure_type: EnclosureType,
+    i: usize,
+    k: Option<f64>,
+) {
+    fn set_k(row: Option<Vec<&mut f64>>, i: usize, k: f64) {
+        if let Some(mut row) = row {
+            let n = row.len();
+            *row[i % n] = k;
+        }
+    }
+
+    match (table, k) {
+        (1, None) => {
+            set.table_1.get_mut(&v_oc).map(|rows| rows.remove(&ec));
+        }
+        (1, Some(k)) => {
+            let row = set
+                .table_1
+                .get_mut(&v_oc)
+                .and_then(|rows| rows.get_mut(&ec));
+            set_k(
+                row.map(|r| {
+                    vec![
+                        &mut r.k1, &mut r.k2, &mut r.k3, &mut r.k4, &mut r.k5, &mut r.k6,
+                        &mut r.k7, &mut r.k8, &mut r.k9, &mut r.k10,
+                    ]
+                }),
+                i,
+                k,
+            );
+        }
+        (2, None) => {
+            set.table_2.remove(&ec);
+        }
+        (2, Some(k)) => {
+            let row = set.table_2.get_mut(&ec);
+            set_k(
+                row.map(|r| {
+                    vec![
+                        &mut r.k1, &mut r.k2, &mut r.k3, &mut r.k4, &mut r.k5, &mut r.k6, &mut r.k7,
+                    ]
+                }),
+                i,
+                k,
+            );
+        }
+        (3..=5, k) => {
+            let rows = match table {
+                3 => &mut set.table_3,
+                4 => &mut set.table_4,
+                _ => &mut set.table_5,
+            };
+            match k {
+                None => {
+                    rows.remove(&ec);
+                }
+                Some(k) => set_k(
+                    rows.get_mut(&ec).map(|r| {
+                        vec![
+                            &mut r.k1, &mut r.k2, &mut r.k3, &mut r.k4, &mut r.k5, &mut r.k6,
+                            &mut r.k7, &mut r.k8, &mut r.k9, &mut r.k10, &mut r.k11, &mut r.k12,
+                            &mut r.k13,
+                        ]
+                    }),
+                    i,
+                    k,
+                ),
+            }
+        }
+        (_, None) => {
+            set.table_7
+                .get_mut(&enclosure_type)
+                .map(|rows| rows.remove(&ec));
+        }
+        (_, Some(k)) => {
+            let row = set
+                .table_7
+                .get_mut(&enclosure_type)
+                .and_then(|rows| rows.get_mut(&ec));
+            set_k(row.map(|r| vec![&mut r.b1, &mut r.b2, &mut r.b3]), i, k);
+        }
+    }
+}
+
+proptest! {
+    /// No finite input causes a panic, and cubicles that are accepted are within the range of the model.
+    #[test]
+    fn test_panic_free(
+        v_oc in value(0.208, 15.0),
+        ec in electrode_configuration(),
+        g in value(6.35, 254.0),
+        d in value(305.0, 3000.0),
+        dim in (value(100.0, 2000.0), value(100.0, 2000.0), value(100.0, 1000.0)),
+        i_bf in value(0.2, 106.0),
+        reduced in any::<bool>(),
+        t in value(1.0, 5000.0),
+        d_x in value(100.0, 10000.0),
+    ) {
+        let c = Cubicle::new(
+            ElectricPotential::new::<kilovolt>(v_oc),
+            ec,
+            Length::new::<millimeter>(g),
+            Length::new::<millimeter>(d),
+            Length::new::<millimeter>(dim.0),
+            Length::new::<millimeter>(dim.1),
+            Length::new::<millimeter>(dim.2),
+        );
+        let c = match c {
+            Ok(c) => c,
+            Err(err) => {
+                let _ = err.to_string();
+                return Ok(());
+            }
+        };
+
+        prop_assert!((0.208..=15.0).contains(&v_oc));
+        prop_assert_eq!(c.hv, v_oc > 0.6);
+        if c.hv {
+            prop_assert!((19.05..=254.0).contains(&g));
+        } else {
+            prop_assert!((6.35..=76.2).contains(&g));
+        }
+        prop_assert!(d >= 305.0);
+        prop_assert!(dim.1 >= 4.0 * g);
+        prop_assert!((0.0..=3.0).contains(&c.cf));
+        let _ = c.to_string();
+
+        let i_arc = match i_arc(&c, ElectricCurrent::new::<kiloampere>(i_bf), reduced) {
+            Ok(i_arc) => i_arc,
+            Err(err) => {
+                let _ = err.to_string();
+                return Ok(());
+            }
+        };
+        prop_assert_eq!(matches!(i_arc, IArc::HighVoltage(_)), c.hv);
+
+        let step = || e_afb(&c, i_arc.clone(), Time::new::<millisecond>(t));
+        let e_afb = step();
+        let _ = e_afb.to_string();
+        let _ = e_afb.warnings();
+        let _ = e_afb.e_at_distance(Length::new::<millimeter>(d_x));
+        let _ = e_afb.distance_for_e(RadiantExposure::new::<joule_per_square_centimeter>(33.47));
+
+        let total = multistep_total(&c, &[e_afb, step()]);
+        prop_assert!(total.is_ok());
+        let _ = total.map(|total| total.to_string());
+    }
+
+    /// No fault current profile causes a panic.
+    #[test]
+    fn test_profile_panic_free(
+        v_oc in value(0.208, 15.0),
+        ec in electrode_configuration(),
+        g in value(6.35, 254.0),
+        segments in prop::collection::vec((value(0.2, 106.0), value(0.0, 500.0)), 0..4),
+        sampled in any::<bool>(),
+        tau in value(1.0, 200.0),
+        step in value(1.0, 50.0),
+        reduced in any::<bool>(),
+        duration in value(0.0, 2000.0),
+    ) {
+        let c = match cubicle(v_oc, ec, g, 914.4) {
+            Some(c) => c,
+            None => return Ok(()),
+        };
+        let duration = Time::new::<millisecond>(duration);
+
+        let profile = if sampled {
+            let i_bf = segments.first().map_or(20.0, |s| s.0);
+            let f = |t: Time| {
+                ElectricCurrent::new::<kiloampere>(i_bf * (-t.get::<millisecond>() / tau).exp())
+            };
+            match FaultCurrentProfile::sample(duration, Time::new::<millisecond>(step), f) {
+                Ok(profile) => profile,
+                Err(err) => {
+                    let _ = err.to_string();
+                    return Ok(());
+                }
+            }
+        } else {
+            FaultCurrentProfile::Segments(
+                segments
+                    .iter()
+                    .map(|&(i_bf, t)| ProfileSegment {
+                        i_bf: ElectricCurrent::new::<kiloampere>(i_bf),
+                        duration: Time::new::<millisecond>(t),
+                    })
+                    .collect(),
+            )
+        };
+
+        match multistep_profile(&c, &profile, reduced, duration) {
+            Ok(result) => {
+                let _ = result.e();
+                let _ = result.afb();
+                let _ = result.total.to_string();
+            }
+            Err(err) => {
+                let _ = err.to_string();
+            }
+        }
+    }
+
+    /// No finite DC input causes a panic, and a result that is returned is finite.
+    #[test]
+    fn test_dc_panic_free(
+        v in value(10.0, 1000.0),
+        i_bf in value(100.0, 100_000.0),
+        model in 0..3,
+        gap in value(1.0, 100.0),
+        k in value(0.01, 100.0),
+        n in value(-1.0, 1.0),
+        enclosure in 0..5,
+        a in value(10.0, 1000.0),
+        k_box in value(0.0, 1.0),
+        t in value(1.0, 2000.0),
+        d in value(100.0, 3000.0),
+    ) {
+        let source = DcSource {
+            v: ElectricPotential::new::<volt>(v),
+            i_bf: ElectricCurrent::new::<ampere>(i_bf),
+        };
+        let model = match model {
+            0 => DcArcModel::MaximumPower,
+            1 => DcArcModel::StokesOppenlander {
+                gap: Length::new::<millimeter>(gap),
+            },
+            _ => DcArcModel::PowerLaw { k, n },
+        };
+        let enclosure = match enclosure {
+            0 => DcEnclosure::OpenAir,
+            1 => DcEnclosure::LvSwitchgear,
+            2 => DcEnclosure::LvMccPanelboard,
+            3 => DcEnclosure::MvSwitchgear,
+            _ => DcEnclosure::Custom {
+                a: Length::new::<millimeter>(a),
+                k: k_box,
+            },
+        };
+
+        match dc_arc_flash(
+            &source,
+            model,
+            enclosure,
+            Time::new::<millisecond>(t),
+            Length::new::<millimeter>(d),
+        ) {
+            Ok(result) => {
+                prop_assert!(result.i_arc.is_finite());
+                prop_assert!(result.e.is_finite());
+                let _ = result.e_at_distance(Length::new::<millimeter>(d));
+                let _ = result.distance_for_e(RadiantExposure::new::<joule_per_square_centimeter>(5.0));
+            }
+            Err(err) => {
+                let _ = err.to_string();
+            }
+        }
+    }
+
+    /// No coefficient set, with rows missing or any coefficient changed, causes a panic once a cubicle accepts it.
+    #[test]
+    fn test_coefficients_panic_free(
+        v_oc in value(0.208, 15.0),
+        ec in electrode_configuration(),
+        g in value(6.35, 254.0),
+        modifications in prop::collection::vec(
+            (
+                1usize..8,
+                prop_oneof![
+                    Just(NominalVoltage::V600),
+                    Just(NominalVoltage::V2700),
+                    Just(NominalVoltage::V14300),
+                ],
+                electrode_configuration(),
+                prop_oneof![Just(EnclosureType::Typical), Just(EnclosureType::Shallow)],
+                0usize..13,
+                prop::option::weighted(0.9, any_value()),
+            ),
+            0..4,
+        ),
+        i_bf in value(0.2, 106.0),
+        reduced in any::<bool>(),
+        t in value(1.0, 5000.0),
+    ) {
+        let c = match cubicle(v_oc, ec, g, 914.4) {
+            Some(c) => c,
+            None => return Ok(()),
+        };
+
+        let mut set = CoefficientSet::default();
+        for (table, v, ec, enclosure_type, i, k) in modifications {
+            modify(&mut set, table, v, ec, enclosure_type, i, k);
+        }
+        let checked = set.check();
+        if let Err(err) = checked {
+            let _ = err.to_string();
+            prop_assert_eq!(err.field(), "coefficients");
+        }
+
+        #[cfg(feature = "serde")]
+        {
+            prop_assert_eq!(
+                CoefficientSet::from_json(set.to_json().unwrap().as_bytes()).is_ok(),
+                checked.is_ok()
+            );
+            if let Ok(s) = set.to_toml() {
+                prop_assert_eq!(CoefficientSet::from_toml(&s).is_ok(), checked.is_ok());
+            }
+        }
+
+        // A set that is checked can still give an enclosure size correction factor outside the range of the model.
+        let c = match c.with_coefficients(Arc::new(set)) {
+            Ok(c) => c,
+            Err(err) => {
+                if checked.is_err() {
+                    prop_assert_eq!(Err(err), checked);
+                }
+                return Ok(());
+            }
+        };
+        prop_assert!(checked.is_ok());
+        match i_arc(&c, ElectricCurrent::new::<kiloampere>(i_bf), reduced) {
+            Ok(i_arc) => {
+                let _ = e_afb(&c, i_arc, Time::new::<millisecond>(t)).to_string();
+            }
+            Err(err) => {
+                let _ = err.to_string();
+            }
+        }
+    }
+}
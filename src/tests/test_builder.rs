@@ -0,0 +1,103 @@
+use crate::{e_afb, i_arc, CubicleBuilder, ElectrodeConfiguration, EnclosureType, EquipmentClass};
+use float_cmp::assert_approx_eq;
+use uom::si::electric_current::{kiloampere, ElectricCurrent};
+use uom::si::electric_potential::{kilovolt, volt};
+use uom::si::f64::{ElectricPotential, Frequency};
+use uom::si::frequency::hertz;
+use uom::si::length::{millimeter, Length};
+use uom::si::radiant_exposure::joule_per_square_centimeter;
+use uom::si::time::{millisecond, Time};
+
+/// Test high voltage calculation example, from Annex D.1, starting from typical 5 kV switchgear.
+#[test]
+fn test_builder_annex_d1() {
+    let cubicle = CubicleBuilder::new(
+        EquipmentClass::Switchgear5kVTall,
+        ElectricPotential::new::<kilovolt>(4.16),
+    )
+    .depth(Length::new::<millimeter>(508.0))
+    .build()
+    .unwrap();
+
+    assert_eq!(cubicle.ec, ElectrodeConfiguration::VCB);
+    assert_approx_eq!(f64, cubicle.g.get::<millimeter>(), 104.0, epsilon = 1e-9);
+    assert_approx_eq!(f64, cubicle.d.get::<millimeter>(), 914.4, epsilon = 1e-9);
+    // D.14
+    assert_approx_eq!(f64, cubicle.cf, 1.284, epsilon = 1e-3);
+
+    let i_arc_min = i_arc(&cubicle, ElectricCurrent::new::<kiloampere>(15.0), true).unwrap();
+    let e_afb_min = e_afb(&cubicle, i_arc_min, Time::new::<millisecond>(223.0));
+    // D.62
+    assert_approx_eq!(
+        f64,
+        e_afb_min.e().get::<joule_per_square_centimeter>(),
+        13.343,
+        epsilon = 1e-3
+    );
+}
+
+#[test]
+fn test_builder_classes() {
+    for class in EquipmentClass::ALL {
+        let v_oc = match class {
+            EquipmentClass::Switchgear15kV | EquipmentClass::Mcc15kV => {
+                ElectricPotential::new::<kilovolt>(13.8)
+            }
+            EquipmentClass::Switchgear5kV
+            | EquipmentClass::Switchgear5kVTall
+            | EquipmentClass::Mcc5kV => ElectricPotential::new::<kilovolt>(4.16),
+            _ => ElectricPotential::new::<volt>(480.0),
+        };
+        let cubicle = CubicleBuilder::new(class, v_oc).build().unwrap();
+
+        let shallow = matches!(
+            class,
+            EquipmentClass::LvMccShallow
+                | EquipmentClass::LvPanelboardShallow
+                | EquipmentClass::CableJunctionBoxShallow
+        );
+        assert_eq!(cubicle.enclosure_type == EnclosureType::Shallow, shallow);
+
+        assert_eq!(class.name().parse::<EquipmentClass>().unwrap(), class);
+    }
+
+    assert_eq!(
+        "lv mcc".parse::<EquipmentClass>().unwrap(),
+        EquipmentClass::LvMcc
+    );
+    assert!("LV Switchboard".parse::<EquipmentClass>().is_err());
+
+    // Overrides are validated
+    let err = CubicleBuilder::new(
+        EquipmentClass::LvSwitchgear,
+        ElectricPotential::new::<volt>(480.0),
+    )
+    .ec(ElectrodeConfiguration::HCB)
+    .gap(Length::new::<millimeter>(104.0))
+    .build()
+    .err()
+    .unwrap();
+    assert_eq!(err.field(), "g");
+
+    // The frequency is checked when it is set
+    let builder = || {
+        CubicleBuilder::new(
+            EquipmentClass::LvSwitchgear,
+            ElectricPotential::new::<volt>(480.0),
+        )
+    };
+    assert!(builder()
+        .frequency(Frequency::new::<hertz>(50.0))
+        .build()
+        .is_ok());
+    let err = builder()
+        .frequency(Frequency::new::<hertz>(400.0))
+        .build()
+        .err()
+        .unwrap();
+    assert_eq!(err.field(), "frequency");
+    assert_eq!(
+        err.to_string(),
+        "frequency 400 Hz is outside the range of the model (50 Hz or 60 Hz)"
+    );
+}
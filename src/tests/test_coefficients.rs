@@ -0,0 +1,149 @@
+use super::d2_cubicle;
+use crate::{
+    e_afb, i_arc, ArcFlashError, ArcFlashMethod, CoefficientSet, ElectrodeConfiguration, Ieee2018,
+    NominalVoltage,
+};
+use float_cmp::assert_approx_eq;
+use std::sync::Arc;
+use uom::si::electric_current::{kiloampere, ElectricCurrent};
+use uom::si::radiant_exposure::joule_per_square_centimeter;
+use uom::si::time::{millisecond, Time};
+
+fn calibrated() -> CoefficientSet {
+    let mut set = CoefficientSet {
+        name: "Calibrated".to_string(),
+        ..Default::default()
+    };
+    set.table_3
+        .get_mut(&ElectrodeConfiguration::VCB)
+        .unwrap()
+        .k1 += 0.1;
+    set
+}
+
+#[test]
+fn test_default_coefficients() {
+    let set = CoefficientSet::default();
+    assert_eq!(set, CoefficientSet::ieee_1584_2018());
+    assert!(set.check().is_ok());
+    assert!(set.is_ieee_1584_2018());
+    assert_approx_eq!(
+        f64,
+        set.table_1_row(ElectrodeConfiguration::VCB, NominalVoltage::V600)
+            .unwrap()
+            .k2,
+        1.035
+    );
+
+    let c = d2_cubicle();
+    let i_bf = ElectricCurrent::new::<kiloampere>(45.0);
+    let t_arc = Time::new::<millisecond>(61.3);
+    let e = e_afb(&c, i_arc(&c, i_bf, false).unwrap(), t_arc).e();
+
+    let c = d2_cubicle().with_coefficients(Arc::new(set)).unwrap();
+    assert_eq!(e_afb(&c, i_arc(&c, i_bf, false).unwrap(), t_arc).e(), e);
+}
+
+/// A calibrated set is used by the equations, and the published set is unchanged.
+#[test]
+fn test_calibrated_coefficients() {
+    let standard = d2_cubicle();
+    let c = d2_cubicle()
+        .with_coefficients(Arc::new(calibrated()))
+        .unwrap();
+    assert!(standard.coefficients().is_ieee_1584_2018());
+    assert!(!c.coefficients().is_ieee_1584_2018());
+    assert_eq!(c.cf, standard.cf);
+
+    let i_bf = ElectricCurrent::new::<kiloampere>(45.0);
+    let t_arc = Time::new::<millisecond>(61.3);
+    let i_arc_standard = i_arc(&standard, i_bf, false).unwrap();
+    let i_arc_calibrated = i_arc(&c, i_bf, false).unwrap();
+    assert_eq!(i_arc_calibrated.i_arc(), i_arc_standard.i_arc());
+
+    // D.2: E = 11.58 J/cm² with the published coefficients.
+    let e_standard = e_afb(&standard, i_arc_standard, t_arc);
+    let e_calibrated = e_afb(&c, i_arc_calibrated, t_arc);
+    assert_approx_eq!(
+        f64,
+        e_standard.e().get::<joule_per_square_centimeter>(),
+        11.58,
+        epsilon = 0.01
+    );
+    assert_approx_eq!(
+        f64,
+        (e_calibrated.e() / e_standard.e()).value,
+        10.0_f64.powf(0.1),
+        epsilon = 1e-9
+    );
+    assert!(e_calibrated.afb() > e_standard.afb());
+
+    assert_eq!(Ieee2018 { cubicle: &standard }.name(), "IEEE 1584-2018");
+    assert_eq!(
+        Ieee2018 { cubicle: &c }.name(),
+        "IEEE 1584-2018 (Calibrated)"
+    );
+}
+
+#[test]
+fn test_incomplete_coefficients() {
+    let mut set = CoefficientSet::default();
+    set.table_2.remove(&ElectrodeConfiguration::HOA);
+    let err = set.check().unwrap_err();
+    assert_eq!(err.to_string(), "Table 2 has no row for HOA");
+    assert_eq!(err.field(), "coefficients");
+    assert_eq!(
+        set.table_2_row(ElectrodeConfiguration::HOA).unwrap_err(),
+        err
+    );
+    assert_eq!(
+        d2_cubicle().with_coefficients(Arc::new(set)).err(),
+        Some(ArcFlashError::InvalidCoefficients {
+            table: 2,
+            v_oc: None,
+            enclosure_type: None,
+            ec: ElectrodeConfiguration::HOA,
+            coefficient: None,
+        })
+    );
+
+    let mut set = CoefficientSet::default();
+    set.table_1
+        .get_mut(&NominalVoltage::V2700)
+        .unwrap()
+        .get_mut(&ElectrodeConfiguration::HCB)
+        .unwrap()
+        .k3 = f64::NAN;
+    let err = set.check().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Table 1 V2700 HCB: coefficient 3 is not finite"
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_coefficients_toml_json() {
+    let set = calibrated();
+
+    let toml = set.to_toml().unwrap();
+    assert!(toml.contains("[table_1.V600.VCB]"));
+    assert_eq!(CoefficientSet::from_toml(&toml).unwrap(), set);
+
+    let json = set.to_json().unwrap();
+    assert_eq!(CoefficientSet::from_json(json.as_bytes()).unwrap(), set);
+
+    assert!(CoefficientSet::from_toml("name = \"Empty\"").is_err());
+
+    // The coefficients are serialized with a cubicle only if they are not the published set.
+    let json = serde_json::to_value(d2_cubicle()).unwrap();
+    assert!(json.get("coefficients").is_none());
+
+    let c = d2_cubicle()
+        .with_coefficients(Arc::new(set.clone()))
+        .unwrap();
+    let json = serde_json::to_value(&c).unwrap();
+    assert_eq!(json["coefficients"]["name"], "Calibrated");
+    let c: crate::Cubicle = serde_json::from_value(json).unwrap();
+    assert_eq!(**c.coefficients(), set);
+}
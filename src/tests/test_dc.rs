@@ -0,0 +1,156 @@
+use crate::units::calorie_per_square_centimeter;
+use crate::{dc_arc_current, dc_arc_flash, DcArcModel, DcEnclosure, DcSource};
+use float_cmp::assert_approx_eq;
+use std::f64::consts::PI;
+use uom::si::electric_current::{ampere, kiloampere, ElectricCurrent};
+use uom::si::electric_potential::{volt, ElectricPotential};
+use uom::si::electrical_resistance::ohm;
+use uom::si::length::{meter, millimeter, Length};
+use uom::si::power::watt;
+use uom::si::radiant_exposure::joule_per_square_centimeter;
+use uom::si::time::{second, Time};
+
+fn battery() -> DcSource {
+    DcSource {
+        v: ElectricPotential::new::<volt>(260.0),
+        i_bf: ElectricCurrent::new::<kiloampere>(4.0),
+    }
+}
+
+#[test]
+fn test_dc_maximum_power() {
+    let t = Time::new::<second>(2.0);
+    let d = Length::new::<millimeter>(457.2);
+    let result = dc_arc_flash(
+        &battery(),
+        DcArcModel::MaximumPower,
+        DcEnclosure::OpenAir,
+        t,
+        d,
+    )
+    .unwrap();
+
+    assert_approx_eq!(f64, result.i_arc.get::<ampere>(), 2000.0);
+    assert_approx_eq!(f64, result.r_arc.get::<ohm>(), 0.065, epsilon = 1e-12);
+    // V × I_bf / 4
+    assert_approx_eq!(f64, result.p_arc.get::<watt>(), 260e3, epsilon = 1e-6);
+
+    // NFPA 70E: 0.01 × V × I_arc × T / D² = 4.975 cal/cm²
+    let nfpa = 0.01 * 260.0 * 2000.0 * 2.0 / 45.72_f64.powi(2);
+    assert_approx_eq!(
+        f64,
+        result.e.get::<calorie_per_square_centimeter>(),
+        nfpa,
+        epsilon = 1e-9
+    );
+    // About 5 % more than a spherical radiator, P × t / (4 π D²).
+    let sphere = 260e3 * 2.0 / (4.0 * PI * 0.4572_f64.powi(2)) / 1e4;
+    assert!(result.e.get::<joule_per_square_centimeter>() > sphere);
+
+    // 1.2 cal/cm² at √(0.01 × V × I_arc × T / 1.2) cm
+    let afb = (0.01 * 260.0 * 2000.0 * 2.0 / 1.2_f64).sqrt() / 100.0;
+    assert_approx_eq!(f64, result.afb.get::<meter>(), afb, epsilon = 1e-9);
+    assert_approx_eq!(
+        f64,
+        result
+            .e_at_distance(result.afb)
+            .get::<joule_per_square_centimeter>(),
+        1.2 * 4.184,
+        epsilon = 1e-9
+    );
+}
+
+#[test]
+fn test_dc_arc_resistance() {
+    let source = battery();
+    let t = Time::new::<second>(2.0);
+    let d = Length::new::<millimeter>(457.2);
+    let gap = Length::new::<millimeter>(25.0);
+
+    let result = dc_arc_flash(
+        &source,
+        DcArcModel::StokesOppenlander { gap },
+        DcEnclosure::OpenAir,
+        t,
+        d,
+    )
+    .unwrap();
+
+    let i = result.i_arc.get::<ampere>();
+    let r_arc = (20.0 + 0.534 * 25.0) / i.powf(0.88);
+    assert_approx_eq!(f64, result.r_arc.get::<ohm>(), r_arc, epsilon = 1e-9);
+    assert_approx_eq!(f64, i * (0.065 + r_arc), 260.0, epsilon = 1e-6);
+    assert!(i < 4000.0);
+
+    // The maximum power method gives an upper bound on the arc power.
+    let max = dc_arc_flash(
+        &source,
+        DcArcModel::MaximumPower,
+        DcEnclosure::OpenAir,
+        t,
+        d,
+    )
+    .unwrap();
+    assert!(result.p_arc < max.p_arc);
+    assert!(result.e < max.e);
+
+    // The same arc resistance, written as a power law.
+    let power_law = dc_arc_flash(
+        &source,
+        DcArcModel::PowerLaw {
+            k: 20.0 + 0.534 * 25.0,
+            n: 0.88,
+        },
+        DcEnclosure::OpenAir,
+        t,
+        d,
+    )
+    .unwrap();
+    assert_approx_eq!(f64, power_law.i_arc.get::<ampere>(), i, epsilon = 1e-6);
+
+    // The arcing current does not depend on the arc duration or the enclosure.
+    let (i_arc, r_arc) = dc_arc_current(&source, DcArcModel::StokesOppenlander { gap }).unwrap();
+    assert_eq!(i_arc, result.i_arc);
+    assert_eq!(r_arc, result.r_arc);
+
+    assert!(dc_arc_flash(
+        &source,
+        DcArcModel::PowerLaw { k: 1.0, n: 1.0 },
+        DcEnclosure::OpenAir,
+        t,
+        d,
+    )
+    .is_err());
+}
+
+#[test]
+fn test_dc_box() {
+    let t = Time::new::<second>(1.0);
+    let d = Length::new::<millimeter>(457.2);
+    let open = dc_arc_flash(
+        &battery(),
+        DcArcModel::MaximumPower,
+        DcEnclosure::OpenAir,
+        t,
+        d,
+    )
+    .unwrap();
+    let boxed = dc_arc_flash(
+        &battery(),
+        DcArcModel::MaximumPower,
+        DcEnclosure::LvSwitchgear,
+        t,
+        d,
+    )
+    .unwrap();
+
+    // k × P × t / (a² + D²)
+    let e = 0.312 * 260e3 / (0.1_f64.powi(2) + 0.4572_f64.powi(2)) / 1e4;
+    assert_approx_eq!(
+        f64,
+        boxed.e.get::<joule_per_square_centimeter>(),
+        e,
+        epsilon = 1e-9
+    );
+    assert!(boxed.e > open.e * 3.0 && boxed.afb > open.afb);
+}
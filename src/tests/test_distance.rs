@@ -0,0 +1,150 @@
+use super::{d1_cubicle, d2_cubicle};
+use crate::units::calorie_per_square_centimeter;
+use crate::{e_afb, i_arc, multistep_total, try_e_afb, ArcFlashError};
+use float_cmp::assert_approx_eq;
+use uom::si::electric_current::{kiloampere, ElectricCurrent};
+use uom::si::f64::RadiantExposure;
+use uom::si::length::{meter, millimeter, Length};
+use uom::si::radiant_exposure::joule_per_square_centimeter;
+use uom::si::time::{millisecond, Time};
+
+/// Test high voltage calculation example, from Annex D.1, at other distances.
+#[test]
+fn test_annex_d1_distance() {
+    let cubicle = d1_cubicle();
+    let i_bf = ElectricCurrent::new::<kiloampere>(15.0);
+
+    let i_arc_max = i_arc(&cubicle, i_bf, false).unwrap();
+    let e_afb_max = e_afb(&cubicle, i_arc_max, Time::new::<millisecond>(197.0));
+
+    // D.32
+    assert_approx_eq!(
+        f64,
+        e_afb_max
+            .e_at_distance(cubicle.d)
+            .unwrap()
+            .get::<joule_per_square_centimeter>(),
+        12.152,
+        epsilon = 1e-3
+    );
+    // D.42
+    assert_approx_eq!(
+        f64,
+        e_afb_max
+            .distance_for_e(RadiantExposure::new::<calorie_per_square_centimeter>(1.2))
+            .unwrap()
+            .get::<millimeter>(),
+        1606.0,
+        epsilon = 1e0
+    );
+
+    let e_450 = e_afb_max
+        .e_at_distance(Length::new::<millimeter>(450.0))
+        .unwrap();
+    let e_1500 = e_afb_max.e_at_distance(Length::new::<meter>(1.5)).unwrap();
+    assert!(e_450 > e_afb_max.e());
+    assert!(e_1500 < e_afb_max.e());
+
+    let d_4 = e_afb_max
+        .distance_for_e(RadiantExposure::new::<calorie_per_square_centimeter>(4.0))
+        .unwrap();
+    let d_8 = e_afb_max
+        .distance_for_e(RadiantExposure::new::<calorie_per_square_centimeter>(8.0))
+        .unwrap();
+    assert!(d_8 < d_4 && d_4 < e_afb_max.afb());
+
+    // A single step multistep calculation is the same as the original.
+    let total = multistep_total(&cubicle, &[e_afb_max]).unwrap();
+    assert_approx_eq!(
+        f64,
+        total.t_arc().get::<millisecond>(),
+        197.0,
+        epsilon = 1e-9
+    );
+    assert_approx_eq!(
+        f64,
+        total
+            .e_at_distance(Length::new::<millimeter>(450.0))
+            .unwrap()
+            .get::<joule_per_square_centimeter>(),
+        e_450.get::<joule_per_square_centimeter>(),
+        epsilon = 1e-9
+    );
+}
+
+/// Test low voltage calculation example, from Annex D.2, at other distances.
+#[test]
+fn test_annex_d2_distance() {
+    let cubicle = d2_cubicle();
+    let i_bf = ElectricCurrent::new::<kiloampere>(45.0);
+
+    let i_arc_max = i_arc(&cubicle, i_bf, false).unwrap();
+    let e_afb_max = e_afb(&cubicle, i_arc_max, Time::new::<millisecond>(61.3));
+
+    // D.95: the energy at the AFB is 1.2 cal/cm².
+    assert_approx_eq!(
+        f64,
+        e_afb_max
+            .e_at_distance(e_afb_max.afb())
+            .unwrap()
+            .get::<calorie_per_square_centimeter>(),
+        1.2,
+        epsilon = 1e-9
+    );
+
+    // Round trip
+    let d = Length::new::<millimeter>(910.0);
+    let e = e_afb_max.e_at_distance(d).unwrap();
+    assert_approx_eq!(
+        f64,
+        e_afb_max.distance_for_e(e).unwrap().get::<millimeter>(),
+        910.0,
+        epsilon = 1e-6
+    );
+}
+
+/// Distances and energies that are not positive and finite, and results used with another cubicle, are errors.
+#[test]
+fn test_distance_errors() {
+    let hv = d1_cubicle();
+    let lv = d2_cubicle();
+    let t = Time::new::<millisecond>(100.0);
+    let i_arc_hv = i_arc(&hv, ElectricCurrent::new::<kiloampere>(15.0), false).unwrap();
+    let i_arc_lv = i_arc(&lv, ElectricCurrent::new::<kiloampere>(45.0), false).unwrap();
+    let e_afb_hv = e_afb(&hv, i_arc_hv.clone(), t);
+    let e_afb_lv = e_afb(&lv, i_arc_lv.clone(), t);
+
+    for d in [0.0, -100.0, f64::INFINITY, f64::NAN] {
+        let err = e_afb_hv
+            .e_at_distance(Length::new::<millimeter>(d))
+            .err()
+            .unwrap();
+        assert!(matches!(
+            err,
+            ArcFlashError::InvalidLength { field: "d", .. }
+        ));
+    }
+    for e_b in [0.0, -1.0, f64::INFINITY] {
+        let err = e_afb_lv
+            .distance_for_e(RadiantExposure::new::<joule_per_square_centimeter>(e_b))
+            .err()
+            .unwrap();
+        assert!(matches!(
+            err,
+            ArcFlashError::InvalidEnergy { field: "e_b", .. }
+        ));
+        assert_eq!(err.field(), "e_b");
+    }
+
+    let mismatched = Some(ArcFlashError::MismatchedResult { v_oc: lv.v_oc });
+    assert_eq!(try_e_afb(&lv, i_arc_hv, t).err(), mismatched);
+    assert_eq!(
+        multistep_total(&lv, std::slice::from_ref(&e_afb_hv)).err(),
+        mismatched
+    );
+    assert_eq!(
+        multistep_total(&hv, &[e_afb_hv, e_afb_lv]).err(),
+        Some(ArcFlashError::MismatchedResult { v_oc: hv.v_oc })
+    );
+    assert!(try_e_afb(&hv, i_arc_lv, t).is_err());
+}
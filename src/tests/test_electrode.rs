@@ -0,0 +1,88 @@
+use super::d1_cubicle;
+use crate::{
+    sensitivity, BusOrientation, DeviceSide, ElectrodeConfiguration, EquipmentAttributes,
+    FixedClearingTimes,
+};
+use float_cmp::assert_approx_eq;
+use uom::si::electric_current::{kiloampere, ElectricCurrent};
+use uom::si::radiant_exposure::joule_per_square_centimeter;
+use uom::si::time::{millisecond, Time};
+
+#[test]
+fn test_electrode_configuration() {
+    use ElectrodeConfiguration::*;
+
+    let mut attributes = EquipmentAttributes {
+        orientation: BusOrientation::Vertical,
+        insulating_barrier: false,
+        enclosed: true,
+        side: Some(DeviceSide::LoadSide),
+    };
+    assert_eq!(attributes.electrode_configuration(), VCB);
+
+    attributes.side = Some(DeviceSide::LineSide);
+    assert_eq!(attributes.electrode_configuration(), VCBB);
+
+    attributes.side = None;
+    attributes.insulating_barrier = true;
+    assert_eq!(attributes.electrode_configuration(), VCBB);
+
+    attributes.orientation = BusOrientation::Horizontal;
+    assert_eq!(attributes.electrode_configuration(), HCB);
+    assert_eq!(attributes.plausible_configurations(), vec![VCB, VCBB, HCB]);
+
+    attributes.enclosed = false;
+    assert_eq!(attributes.electrode_configuration(), HOA);
+    attributes.orientation = BusOrientation::Vertical;
+    assert_eq!(attributes.electrode_configuration(), VOA);
+    assert_eq!(attributes.plausible_configurations(), vec![VOA, HOA]);
+}
+
+/// Sensitivity of the high voltage calculation example, from Annex D.1, to the electrode configuration.
+#[test]
+fn test_sensitivity() {
+    let cubicle = d1_cubicle();
+    let attributes = EquipmentAttributes {
+        orientation: BusOrientation::Vertical,
+        insulating_barrier: false,
+        enclosed: true,
+        side: None,
+    };
+    let clearing = FixedClearingTimes {
+        full: Time::new::<millisecond>(197.0),
+        reduced: Time::new::<millisecond>(223.0),
+    };
+
+    let s = sensitivity(
+        &cubicle,
+        &attributes,
+        ElectricCurrent::new::<kiloampere>(15.0),
+        &clearing,
+    );
+    assert_eq!(s.recommended, ElectrodeConfiguration::VCB);
+    assert_eq!(s.results.len(), 3);
+
+    // D.62
+    let recommended = s.recommended().unwrap().as_ref().unwrap();
+    assert_approx_eq!(
+        f64,
+        recommended
+            .governing()
+            .e_afb
+            .e()
+            .get::<joule_per_square_centimeter>(),
+        13.343,
+        epsilon = 1e-3
+    );
+
+    let (_, max) = s.max().unwrap();
+    let (_, min) = s.min().unwrap();
+    assert!(max > min);
+    assert_approx_eq!(
+        f64,
+        s.spread().unwrap().get::<joule_per_square_centimeter>(),
+        (max - min).get::<joule_per_square_centimeter>(),
+        epsilon = 1e-9
+    );
+    assert!(s.to_string().starts_with("Recommended EC = VCB"));
+}
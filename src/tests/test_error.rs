@@ -0,0 +1,119 @@
+use super::d1_cubicle;
+use crate::{i_arc, ArcFlashError, Cubicle, ElectrodeConfiguration};
+use uom::si::electric_current::{kiloampere, ElectricCurrent};
+use uom::si::electric_potential::{kilovolt, ElectricPotential};
+use uom::si::frequency::{hertz, Frequency};
+use uom::si::length::{millimeter, Length};
+
+fn cubicle(v_oc: f64, g: f64, d: f64, width: f64) -> Result<Cubicle, ArcFlashError> {
+    Cubicle::new(
+        ElectricPotential::new::<kilovolt>(v_oc),
+        ElectrodeConfiguration::VCB,
+        Length::new::<millimeter>(g),
+        Length::new::<millimeter>(d),
+        Length::new::<millimeter>(1143.0),
+        Length::new::<millimeter>(width),
+        Length::new::<millimeter>(508.0),
+    )
+}
+
+#[test]
+fn test_cubicle_errors() {
+    let mm = Length::new::<millimeter>;
+
+    assert_eq!(
+        cubicle(4.16, 10.0, 914.4, 762.0).err(),
+        Some(ArcFlashError::GapOutOfRange {
+            g: mm(10.0),
+            min: mm(19.05),
+            max: mm(254.0),
+        })
+    );
+    let err = cubicle(0.48, 80.0, 914.4, 762.0).err().unwrap();
+    assert_eq!(
+        err,
+        ArcFlashError::GapOutOfRange {
+            g: mm(80.0),
+            min: mm(6.35),
+            max: mm(76.2),
+        }
+    );
+    assert_eq!(err.field(), "g");
+    assert!(err
+        .to_string()
+        .starts_with("busbar gap G must be <= 76.2 mm"));
+
+    assert_eq!(
+        cubicle(4.16, 104.0, 300.0, 762.0).err(),
+        Some(ArcFlashError::WorkingDistanceTooSmall {
+            d: mm(300.0),
+            min: mm(305.0),
+        })
+    );
+
+    let err = cubicle(4.16, 104.0, 914.4, 400.0).err().unwrap();
+    assert_eq!(
+        err,
+        ArcFlashError::EnclosureTooNarrow {
+            width: mm(400.0),
+            min: mm(416.0),
+        }
+    );
+    assert_eq!(err.field(), "width");
+
+    let err = cubicle(4.16, 104.0, f64::INFINITY, 762.0).err().unwrap();
+    assert!(matches!(
+        err,
+        ArcFlashError::InvalidLength { field: "d", .. }
+    ));
+    assert_eq!(err.field(), "d");
+
+    for v_oc in [0.1, 0.2, 20.0, f64::NAN] {
+        let err = cubicle(v_oc, 104.0, 914.4, 762.0).err().unwrap();
+        assert!(matches!(err, ArcFlashError::VoltageOutOfRange { .. }));
+        assert_eq!(err.field(), "v_oc");
+    }
+
+    for f in [50.0, 60.0] {
+        let c = d1_cubicle()
+            .with_frequency(Frequency::new::<hertz>(f))
+            .unwrap();
+        assert_eq!(c.frequency(), Some(Frequency::new::<hertz>(f)));
+    }
+    for f in [0.0, 25.0, 400.0, f64::NAN] {
+        let err = d1_cubicle()
+            .with_frequency(Frequency::new::<hertz>(f))
+            .err()
+            .unwrap();
+        assert!(matches!(err, ArcFlashError::FrequencyOutOfRange { .. }));
+        assert_eq!(err.field(), "frequency");
+    }
+}
+
+#[test]
+fn test_i_arc_errors() {
+    let ka = ElectricCurrent::new::<kiloampere>;
+
+    let hv = d1_cubicle();
+    assert_eq!(
+        i_arc(&hv, ka(70.0), false).err(),
+        Some(ArcFlashError::IbfOutOfRange {
+            i_bf: ka(70.0),
+            min: ka(0.2),
+            max: ka(65.0),
+        })
+    );
+
+    let lv = cubicle(0.48, 32.0, 609.6, 610.0).unwrap();
+    let err = i_arc(&lv, ka(0.3), true).err().unwrap();
+    assert_eq!(
+        err,
+        ArcFlashError::IbfOutOfRange {
+            i_bf: ka(0.3),
+            min: ka(0.5),
+            max: ka(106.0),
+        }
+    );
+    assert_eq!(err.field(), "i_bf");
+    assert!(i_arc(&lv, ka(70.0), false).is_ok());
+}
@@ -0,0 +1,101 @@
+use super::d2_cubicle;
+use crate::{
+    multistep_profile, profile_clearing_time, FaultCurrentProfile, Generator, InverseCurve,
+    InverseTimeElement,
+};
+use float_cmp::assert_approx_eq;
+use uom::si::electric_current::{ampere, ElectricCurrent};
+use uom::si::electric_potential::{volt, ElectricPotential};
+use uom::si::time::{millisecond, second, Time};
+
+fn generator(field_forcing: Option<f64>) -> Generator {
+    Generator {
+        kva: 1000.0,
+        v: ElectricPotential::new::<volt>(480.0),
+        xd_subtransient: 0.15,
+        xd_transient: 0.25,
+        xd: 2.0,
+        t_subtransient: Time::new::<millisecond>(15.0),
+        t_transient: Time::new::<millisecond>(300.0),
+        field_forcing,
+    }
+}
+
+#[test]
+fn test_generator_decrement() {
+    let gen = generator(None);
+    let i_rated = gen.i_rated().get::<ampere>();
+    // 1000 kVA / (√3 × 480 V) = 1202.8 A
+    assert_approx_eq!(f64, i_rated, 1202.8, epsilon = 0.1);
+
+    let i = |gen: &Generator, t: f64| gen.current(Time::new::<second>(t)).get::<ampere>();
+    assert_approx_eq!(f64, i(&gen, 0.0), i_rated / 0.15, epsilon = 1e-6);
+    assert_approx_eq!(f64, i(&gen, 10.0), i_rated / 2.0, epsilon = 1e-3);
+    // Subtransient component has decayed, transient component remains.
+    let expected = i_rated
+        * ((1.0 / 0.15 - 1.0 / 0.25) * (-100.0_f64 / 15.0).exp()
+            + (1.0 / 0.25 - 1.0 / 2.0) * (-1.0_f64 / 3.0).exp()
+            + 1.0 / 2.0);
+    assert_approx_eq!(f64, i(&gen, 0.1), expected, epsilon = 1e-6);
+
+    let forced = generator(Some(3.0));
+    assert_approx_eq!(f64, i(&forced, 0.0), i_rated / 0.15, epsilon = 1e-6);
+    assert_approx_eq!(f64, i(&forced, 10.0), 3.0 * i_rated, epsilon = 1e-3);
+
+    let mut bad = generator(None);
+    bad.xd_transient = 0.1;
+    assert!(bad
+        .profile(Time::new::<second>(1.0), Time::new::<millisecond>(10.0))
+        .is_err());
+}
+
+#[test]
+fn test_generator_clearing_time() {
+    let cubicle = d2_cubicle();
+    let device = InverseTimeElement {
+        curve: InverseCurve::IeeeVeryInverse,
+        pickup: ElectricCurrent::new::<ampere>(400.0),
+        time_dial: 0.2,
+    };
+    let max = Time::new::<second>(2.0);
+    let step = Time::new::<millisecond>(5.0);
+
+    let gen = generator(None);
+    let profile = gen.profile(max, step).unwrap();
+    let t = profile_clearing_time(&cubicle, &profile, false, &device, max)
+        .unwrap()
+        .unwrap();
+
+    // Slower than if the subtransient current were sustained.
+    let initial = FaultCurrentProfile::constant(gen.current(Time::new::<second>(0.0)));
+    let t_initial = profile_clearing_time(&cubicle, &initial, false, &device, max)
+        .unwrap()
+        .unwrap();
+    assert!(t > t_initial);
+
+    // Field forcing sustains the current, so the device clears sooner.
+    let forced = generator(Some(3.0)).profile(max, step).unwrap();
+    let t_forced = profile_clearing_time(&cubicle, &forced, false, &device, max)
+        .unwrap()
+        .unwrap();
+    assert!(t_forced < t);
+
+    let result = multistep_profile(&cubicle, &profile, false, t).unwrap();
+    assert_approx_eq!(
+        f64,
+        result.total.t_arc().get::<second>(),
+        t.get::<second>(),
+        epsilon = 1e-9
+    );
+
+    // A device that does not pick up does not clear.
+    let device = InverseTimeElement {
+        pickup: ElectricCurrent::new::<ampere>(20000.0),
+        ..device
+    };
+    assert!(
+        profile_clearing_time(&cubicle, &profile, false, &device, max)
+            .unwrap()
+            .is_none()
+    );
+}
@@ -0,0 +1,314 @@
+use crate::{
+    compare_editions, e_afb_2002, evaluate_worst_case, i_arc_2002, Breaker2002, CubicleBuilder,
+    DefiniteTimeElement, Device2002, DeviceEquation, DeviceEquationRange, Edition, Equipment2002,
+    EquipmentClass, Fuse2002, Grounding, Inputs2002,
+};
+use float_cmp::assert_approx_eq;
+use uom::si::electric_current::{ampere, kiloampere, ElectricCurrent};
+use uom::si::electric_potential::{kilovolt, volt, ElectricPotential};
+use uom::si::length::{millimeter, Length};
+use uom::si::radiant_exposure::joule_per_square_centimeter;
+use uom::si::time::{millisecond, Time};
+
+fn lv_switchgear() -> Inputs2002 {
+    Inputs2002 {
+        v_oc: ElectricPotential::new::<volt>(480.0),
+        g: Length::new::<millimeter>(32.0),
+        d: Length::new::<millimeter>(610.0),
+        enclosed: true,
+        equipment: Equipment2002::Switchgear,
+        grounding: Grounding::Grounded,
+    }
+}
+
+#[test]
+fn test_2002_low_voltage() {
+    let inputs = lv_switchgear();
+    let i_arc = i_arc_2002(&inputs, ElectricCurrent::new::<kiloampere>(30.0)).unwrap();
+    assert_approx_eq!(f64, i_arc.i_arc.get::<kiloampere>(), 15.724, epsilon = 1e-3);
+    assert_approx_eq!(
+        f64,
+        i_arc.i_arc_reduced.unwrap().get::<kiloampere>(),
+        0.85 * i_arc.i_arc.get::<kiloampere>(),
+        epsilon = 1e-9
+    );
+
+    let e_afb = e_afb_2002(&inputs, i_arc.i_arc, Time::new::<millisecond>(100.0)).unwrap();
+    assert_approx_eq!(f64, e_afb.e_n, 4.578, epsilon = 1e-3);
+    assert_approx_eq!(f64, e_afb.cf, 1.5);
+    assert_approx_eq!(f64, e_afb.x, 1.473);
+    assert_approx_eq!(
+        f64,
+        e_afb.e.get::<joule_per_square_centimeter>(),
+        14.366,
+        epsilon = 1e-3
+    );
+    assert_approx_eq!(f64, e_afb.afb.get::<millimeter>(), 1245.3, epsilon = 0.1);
+
+    // Open air, ungrounded: lower arcing current and energy.
+    let mut open = inputs;
+    open.enclosed = false;
+    open.equipment = Equipment2002::OpenAir;
+    open.grounding = Grounding::Ungrounded;
+    let i_arc_open = i_arc_2002(&open, ElectricCurrent::new::<kiloampere>(30.0)).unwrap();
+    assert!(i_arc_open.i_arc < i_arc.i_arc);
+}
+
+#[test]
+fn test_2002_high_voltage() {
+    let inputs = Inputs2002 {
+        v_oc: ElectricPotential::new::<kilovolt>(13.8),
+        g: Length::new::<millimeter>(152.0),
+        d: Length::new::<millimeter>(910.0),
+        enclosed: true,
+        equipment: Equipment2002::Switchgear,
+        grounding: Grounding::Ungrounded,
+    };
+    let i_arc = i_arc_2002(&inputs, ElectricCurrent::new::<kiloampere>(20.0)).unwrap();
+    assert_approx_eq!(f64, i_arc.i_arc.get::<kiloampere>(), 19.184, epsilon = 1e-3);
+    assert_eq!(i_arc.i_arc_reduced, None);
+
+    let e_afb = e_afb_2002(&inputs, i_arc.i_arc, Time::new::<millisecond>(500.0)).unwrap();
+    assert_approx_eq!(
+        f64,
+        e_afb.e.get::<joule_per_square_centimeter>(),
+        70.723,
+        epsilon = 1e-3
+    );
+
+    let mut out_of_range = inputs;
+    out_of_range.v_oc = ElectricPotential::new::<kilovolt>(33.0);
+    assert!(i_arc_2002(&out_of_range, ElectricCurrent::new::<kiloampere>(20.0)).is_err());
+    assert!(i_arc_2002(&inputs, ElectricCurrent::new::<kiloampere>(120.0)).is_err());
+}
+
+#[test]
+fn test_compare_editions() {
+    let cubicle = CubicleBuilder::new(
+        EquipmentClass::LvSwitchgear,
+        ElectricPotential::new::<volt>(480.0),
+    )
+    .build()
+    .unwrap();
+    let i_bf = ElectricCurrent::new::<kiloampere>(30.0);
+    let device = DefiniteTimeElement {
+        pickup: ElectricCurrent::new::<ampere>(1000.0),
+        delay: Time::new::<millisecond>(100.0),
+    };
+    let edition_2002 = Edition::Ieee2002 {
+        equipment: EquipmentClass::LvSwitchgear.into(),
+        grounding: Grounding::Grounded,
+        device: None,
+    };
+
+    let results = compare_editions(
+        &[Edition::Ieee2018, edition_2002],
+        &cubicle,
+        i_bf,
+        &device,
+        None,
+    );
+    let (r2018, r2002) = (results[0].as_ref().unwrap(), results[1].as_ref().unwrap());
+
+    let worst = evaluate_worst_case(&cubicle, i_bf, &device).unwrap();
+    assert_eq!(r2018.edition, Edition::Ieee2018);
+    assert_eq!(r2018.e, worst.governing().e_afb.e());
+
+    let inputs = Inputs2002::from_cubicle(&cubicle, Equipment2002::Switchgear, Grounding::Grounded);
+    assert!(inputs.enclosed);
+    let i_arc = i_arc_2002(&inputs, i_bf).unwrap();
+    let e_afb = e_afb_2002(&inputs, i_arc.i_arc, Time::new::<millisecond>(100.0)).unwrap();
+    // The same clearing time for both currents, so the full arcing current governs.
+    assert_eq!(r2002.i_arc, i_arc.i_arc);
+    assert_eq!(r2002.e, e_afb.e);
+    assert!(r2002.to_string().starts_with("IEEE 1584-2002"));
+}
+
+#[test]
+fn test_device_equation() {
+    // Illustrative coefficients only.
+    let equation = DeviceEquation {
+        ranges: vec![
+            DeviceEquationRange {
+                i_bf_min: ElectricCurrent::new::<kiloampere>(10.0),
+                i_bf_max: ElectricCurrent::new::<kiloampere>(50.0),
+                c: 0.0,
+                a: -0.1,
+                b: 6.0,
+            },
+            DeviceEquationRange {
+                i_bf_min: ElectricCurrent::new::<kiloampere>(50.0),
+                i_bf_max: ElectricCurrent::new::<kiloampere>(100.0),
+                c: 0.0,
+                a: 0.0,
+                b: 1.0,
+            },
+        ],
+    };
+
+    let e = equation
+        .e(ElectricCurrent::new::<kiloampere>(20.0))
+        .unwrap();
+    assert_approx_eq!(
+        f64,
+        e.get::<joule_per_square_centimeter>(),
+        4.184 * 4.0,
+        epsilon = 1e-9
+    );
+    assert!(equation
+        .e(ElectricCurrent::new::<kiloampere>(5.0))
+        .is_none());
+
+    // 455 mm scaled to 610 mm in LV switchgear.
+    let e_610 = equation
+        .e_at_distance(&lv_switchgear(), ElectricCurrent::new::<kiloampere>(20.0))
+        .unwrap()
+        .unwrap();
+    assert_approx_eq!(
+        f64,
+        e_610.get::<joule_per_square_centimeter>(),
+        4.184 * 4.0 * (455.0_f64 / 610.0).powf(1.473),
+        epsilon = 1e-9
+    );
+
+    // The working distance is checked.
+    let inputs = Inputs2002 {
+        d: Length::new::<millimeter>(0.0),
+        ..lv_switchgear()
+    };
+    assert!(equation
+        .e_at_distance(&inputs, ElectricCurrent::new::<kiloampere>(20.0))
+        .is_err());
+}
+
+#[test]
+fn test_2002_fuse_and_breaker_equations() {
+    let ka = ElectricCurrent::new::<kiloampere>;
+    let e = |equation: &DeviceEquation, i_bf: f64| {
+        equation
+            .e(ka(i_bf))
+            .unwrap()
+            .get::<joule_per_square_centimeter>()
+    };
+
+    // s5.6 e): Class RK1 401 A - 600 A.
+    let rk1 = DeviceEquation::fuse(Fuse2002::ClassRk1_600);
+    assert_approx_eq!(
+        f64,
+        e(&rk1, 10.0),
+        4.184 * (-3.0545 * 10.0 + 43.364),
+        epsilon = 1e-9
+    );
+    assert_approx_eq!(f64, e(&rk1, 15.0), 2.510, epsilon = 1e-9);
+    assert_approx_eq!(f64, e(&rk1, 50.0), 1.046, epsilon = 1e-9);
+    assert!(rk1.e(ka(5.0)).is_none());
+    assert!(rk1.e(ka(110.0)).is_none());
+
+    // s5.6 c): Class L 801 A - 1200 A, quadratic between 22.6 kA and 44.1 kA.
+    let class_l = DeviceEquation::fuse(Fuse2002::ClassL1200);
+    assert_approx_eq!(
+        f64,
+        e(&class_l, 30.0),
+        4.184 * (0.0143 * 900.0 - 1.3919 * 30.0 + 34.045),
+        epsilon = 1e-9
+    );
+    assert!(DeviceEquation::fuse(Fuse2002::ClassL2000)
+        .e(ka(20.0))
+        .is_none());
+
+    // The pieces of each fuse's equation meet at their boundaries.
+    for fuse in [
+        Fuse2002::ClassL2000,
+        Fuse2002::ClassL1600,
+        Fuse2002::ClassL800,
+        Fuse2002::ClassRk1_600,
+        Fuse2002::ClassRk1_400,
+        Fuse2002::ClassRk1_200,
+        Fuse2002::ClassRk1_100,
+    ] {
+        let equation = DeviceEquation::fuse(fuse);
+        for pair in equation.ranges.windows(2) {
+            assert_eq!(pair[0].i_bf_max, pair[1].i_bf_min);
+            let i = pair[0].i_bf_max.get::<kiloampere>();
+            let e = |r: &DeviceEquationRange| 4.184 * (r.c * i * i + r.a * i + r.b);
+            assert_approx_eq!(f64, e(&pair[0]), e(&pair[1]), epsilon = 0.05);
+        }
+    }
+
+    // Table 5: LVPCB with LI trip, 30 kA.
+    let v = ElectricPotential::new::<volt>;
+    let lvpcb = DeviceEquation::breaker(Breaker2002::LvpcbLi, v(480.0)).unwrap();
+    assert_approx_eq!(f64, e(&lvpcb, 30.0), 0.636 * 30.0 + 3.670, epsilon = 1e-9);
+    let mccb = DeviceEquation::breaker(Breaker2002::Mccb400Tm, v(600.0)).unwrap();
+    assert_approx_eq!(f64, e(&mccb, 10.0), 0.271 * 10.0 + 0.180, epsilon = 1e-9);
+    assert!(mccb.e(ka(0.5)).is_none());
+    assert!(DeviceEquation::breaker(Breaker2002::Mccb400Tm, v(520.0)).is_err());
+}
+
+/// An edition with a device equation uses it within its ranges, and the time-current curve outside them.
+#[test]
+fn test_edition_device_equation() {
+    let cubicle = CubicleBuilder::new(
+        EquipmentClass::LvSwitchgear,
+        ElectricPotential::new::<volt>(480.0),
+    )
+    .build()
+    .unwrap();
+    let device = DefiniteTimeElement {
+        pickup: ElectricCurrent::new::<ampere>(1000.0),
+        delay: Time::new::<millisecond>(100.0),
+    };
+    let edition = |device| Edition::Ieee2002 {
+        equipment: Equipment2002::Switchgear,
+        grounding: Grounding::Grounded,
+        device,
+    };
+    let fuse = Some(Device2002::Fuse(Fuse2002::ClassRk1_600));
+    let inputs = Inputs2002::from_cubicle(&cubicle, Equipment2002::Switchgear, Grounding::Grounded);
+
+    // s5.6 e): 1.046 J/cm² at 455 mm from 22.6 kA.
+    let i_bf = ElectricCurrent::new::<kiloampere>(30.0);
+    let results = compare_editions(
+        &[edition(fuse), edition(None)],
+        &cubicle,
+        i_bf,
+        &device,
+        None,
+    );
+    let (with_fuse, without) = (results[0].as_ref().unwrap(), results[1].as_ref().unwrap());
+    let e = DeviceEquation::fuse(Fuse2002::ClassRk1_600)
+        .e_at_distance(&inputs, i_bf)
+        .unwrap()
+        .unwrap();
+    assert_approx_eq!(
+        f64,
+        with_fuse.e.get::<joule_per_square_centimeter>(),
+        e.get::<joule_per_square_centimeter>(),
+        epsilon = 1e-9
+    );
+    assert!(with_fuse.e < without.e);
+
+    // Below the ranges of the equation, the time-current curve is used.
+    let i_bf = ElectricCurrent::new::<kiloampere>(5.0);
+    let results = compare_editions(
+        &[edition(fuse), edition(None)],
+        &cubicle,
+        i_bf,
+        &device,
+        None,
+    );
+    assert_eq!(
+        results[0].as_ref().unwrap().e,
+        results[1].as_ref().unwrap().e
+    );
+
+    // Table 5 has no breaker equations at 520 V.
+    let cubicle = CubicleBuilder::new(
+        EquipmentClass::LvSwitchgear,
+        ElectricPotential::new::<volt>(520.0),
+    )
+    .build()
+    .unwrap();
+    let breaker = Some(Device2002::Breaker(Breaker2002::Mccb400Tm));
+    assert!(compare_editions(&[edition(breaker)], &cubicle, i_bf, &device, None)[0].is_err());
+}
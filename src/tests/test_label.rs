@@ -0,0 +1,70 @@
+use super::d2_cubicle;
+use crate::units::calorie_per_square_centimeter;
+use crate::{
+    e_afb, i_arc, signal_word, EnergyUnit, LabelInfo, LabelTemplate, LengthUnit, PpeTable,
+    SignalWord,
+};
+use uom::si::electric_current::{kiloampere, ElectricCurrent};
+use uom::si::electric_potential::{kilovolt, volt, ElectricPotential};
+use uom::si::length::{millimeter, Length};
+use uom::si::time::{millisecond, Time};
+
+/// Label for the low voltage calculation example, from Annex D.2.
+#[test]
+fn test_annex_d2_label() {
+    let cubicle = d2_cubicle();
+    let i_bf = ElectricCurrent::new::<kiloampere>(45.0);
+
+    let i_arc_min = i_arc(&cubicle, i_bf, true).unwrap();
+    let e_afb_min = e_afb(&cubicle, i_arc_min, Time::new::<millisecond>(319.0));
+    let ppe = PpeTable::nfpa_70e();
+
+    let info = LabelInfo {
+        equipment: "MCC-1 <Bus A> & feeders",
+        nominal_voltage: ElectricPotential::new::<volt>(480.0),
+        working_distance: cubicle.d,
+        ppe: &ppe,
+    };
+
+    let svg = LabelTemplate::default()
+        .render_svg(&e_afb_min, &info)
+        .unwrap();
+    assert!(svg.starts_with("<svg"));
+    assert!(svg.contains("WARNING"));
+    // D.103: 53.156 J/cm² = 12.7 cal/cm²
+    assert!(svg.contains("Incident energy: 12.7 cal/cm² at 610 mm"));
+    assert!(svg.contains("Arc flash boundary: 2669 mm"));
+    assert!(svg.contains("PPE Category 3"));
+    assert!(svg.contains("Restricted approach: 300 mm"));
+    assert!(svg.contains("MCC-1 &lt;Bus A&gt; &amp; feeders"));
+
+    let template = LabelTemplate {
+        length_unit: LengthUnit::Inch,
+        energy_unit: EnergyUnit::JoulePerSquareCentimeter,
+        ..Default::default()
+    };
+    let svg = template.render_svg(&e_afb_min, &info).unwrap();
+    assert!(svg.contains("Incident energy: 53.2 J/cm² at 24.0 in"));
+
+    assert_eq!(
+        signal_word(e_afb_min.e(), ElectricPotential::new::<kilovolt>(4.16)),
+        SignalWord::Danger
+    );
+
+    // At a longer working distance the PPE and minimum arc rating are selected for the energy on the label.
+    let info = LabelInfo {
+        working_distance: Length::new::<millimeter>(1200.0),
+        ..info
+    };
+    let e = e_afb_min.e_at_distance(info.working_distance).unwrap();
+    let selection = ppe.select(e);
+    let ppe_name = &selection.ppe.as_ref().unwrap().name;
+    assert_ne!(ppe_name, "PPE Category 3");
+    let svg = LabelTemplate::default()
+        .render_svg(&e_afb_min, &info)
+        .unwrap();
+    let energy = format!("{:.1} cal/cm²", e.get::<calorie_per_square_centimeter>());
+    assert!(svg.contains(&format!("Incident energy: {} at 1200 mm", energy)));
+    assert!(svg.contains(&format!("Minimum arc rating: {}", energy)));
+    assert!(svg.contains(&format!("PPE: {}", ppe_name)));
+}
@@ -0,0 +1,70 @@
+use crate::lee_e_afb;
+use float_cmp::assert_approx_eq;
+use uom::si::electric_current::{kiloampere, ElectricCurrent};
+use uom::si::electric_potential::{kilovolt, ElectricPotential};
+use uom::si::length::{millimeter, Length};
+use uom::si::radiant_exposure::joule_per_square_centimeter;
+use uom::si::time::{millisecond, Time};
+
+/// 33 kV switchyard.
+#[test]
+fn test_lee() {
+    let result = lee_e_afb(
+        ElectricPotential::new::<kilovolt>(33.0),
+        ElectricCurrent::new::<kiloampere>(20.0),
+        Time::new::<millisecond>(100.0),
+        Length::new::<millimeter>(914.4),
+    )
+    .unwrap();
+
+    // 2.142e6 × 33 × 20 × 0.1 / 914.4² = 169.08 J/cm²
+    assert_approx_eq!(
+        f64,
+        result.e.get::<joule_per_square_centimeter>(),
+        169.08,
+        epsilon = 0.01
+    );
+    // √(2.142e6 × 33 × 20 × 0.1 / 5.0208) = 5306 mm
+    assert_approx_eq!(f64, result.afb.get::<millimeter>(), 5306.3, epsilon = 0.1);
+    assert_approx_eq!(
+        f64,
+        result
+            .e_at_distance(result.afb)
+            .get::<joule_per_square_centimeter>(),
+        5.0208,
+        epsilon = 1e-9
+    );
+    assert!(result.to_string().starts_with("Lee method"));
+
+    assert!(lee_e_afb(
+        ElectricPotential::new::<kilovolt>(66.0),
+        ElectricCurrent::new::<kiloampere>(0.0),
+        Time::new::<millisecond>(100.0),
+        Length::new::<millimeter>(914.4),
+    )
+    .is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_lee_serde() {
+    let result = lee_e_afb(
+        ElectricPotential::new::<kilovolt>(66.0),
+        ElectricCurrent::new::<kiloampere>(10.0),
+        Time::new::<millisecond>(200.0),
+        Length::new::<millimeter>(1500.0),
+    )
+    .unwrap();
+
+    let json = serde_json::to_value(&result).unwrap();
+    assert_eq!(json["method"], "lee");
+    assert_approx_eq!(f64, json["v_oc_kv"].as_f64().unwrap(), 66.0, epsilon = 1e-9);
+
+    let back: crate::LeeEAfb = serde_json::from_value(json).unwrap();
+    assert_approx_eq!(
+        f64,
+        back.e.get::<joule_per_square_centimeter>(),
+        result.e.get::<joule_per_square_centimeter>(),
+        epsilon = 1e-9
+    );
+}
@@ -0,0 +1,130 @@
+use super::d2_cubicle;
+use crate::{
+    evaluate_method, evaluate_worst_case, ArcFlashMethod, ArcingCurrentCase, Dc, DcArcModel,
+    DcEnclosure, DefiniteTimeElement, Ieee2018, LabelInfo, LabelTemplate, Lee, LvTripUnit,
+    PpeTable, Warning,
+};
+use float_cmp::assert_approx_eq;
+use uom::si::electric_current::{ampere, kiloampere, ElectricCurrent};
+use uom::si::electric_potential::{volt, ElectricPotential};
+use uom::si::radiant_exposure::joule_per_square_centimeter;
+use uom::si::time::{millisecond, second, Time};
+
+/// The 2018 method through the trait gives the same result as `evaluate_worst_case`.
+#[test]
+fn test_method_2018() {
+    let cubicle = d2_cubicle();
+    let i_bf = ElectricCurrent::new::<kiloampere>(45.0);
+    let mut device = LvTripUnit::new(
+        ElectricCurrent::new::<ampere>(800.0),
+        Time::new::<second>(10.0),
+    );
+    device.instantaneous_pickup = Some(ElectricCurrent::new::<kiloampere>(20.0));
+
+    let method = Ieee2018 { cubicle: &cubicle };
+    let evaluation = evaluate_method(&method, i_bf, &device, None).unwrap();
+    let worst = evaluate_worst_case(&cubicle, i_bf, &device).unwrap();
+
+    assert_eq!(evaluation.method, "IEEE 1584-2018");
+    assert_eq!(evaluation.cases.len(), 2);
+    assert_eq!(evaluation.cases[0].case, ArcingCurrentCase::Full);
+    assert_eq!(evaluation.cases[0].result.e(), worst.full.e_afb.e());
+    assert_eq!(evaluation.cases[1].result.e(), worst.reduced.e_afb.e());
+    assert_eq!(evaluation.governing().case, worst.governing);
+    assert_eq!(
+        evaluation.governing().result.afb(),
+        worst.governing().e_afb.afb()
+    );
+}
+
+/// Methods can be swapped without changing the evaluation or labelling code.
+#[test]
+fn test_swap_methods() {
+    let cubicle = d2_cubicle();
+    let i_bf = ElectricCurrent::new::<kiloampere>(20.0);
+    let device = DefiniteTimeElement {
+        pickup: ElectricCurrent::new::<ampere>(100.0),
+        delay: Time::new::<millisecond>(100.0),
+    };
+
+    let methods: Vec<Box<dyn ArcFlashMethod>> = vec![
+        Box::new(Ieee2018 { cubicle: &cubicle }),
+        Box::new(Lee {
+            v_oc: cubicle.v_oc,
+            d: cubicle.d,
+        }),
+        Box::new(Dc {
+            v: ElectricPotential::new::<volt>(480.0),
+            model: DcArcModel::MaximumPower,
+            enclosure: DcEnclosure::LvSwitchgear,
+            d: cubicle.d,
+        }),
+    ];
+
+    for method in &methods {
+        let evaluation = evaluate_method(method.as_ref(), i_bf, &device, None).unwrap();
+        let governing = evaluation.governing();
+        assert_approx_eq!(f64, governing.result.t_arc().get::<millisecond>(), 100.0);
+        assert_approx_eq!(
+            f64,
+            governing
+                .result
+                .e_at_distance(governing.result.afb())
+                .unwrap()
+                .get::<joule_per_square_centimeter>(),
+            5.0208,
+            epsilon = 1e-3
+        );
+
+        let ppe = PpeTable::nfpa_70e();
+        let info = LabelInfo {
+            equipment: "SWBD-1",
+            nominal_voltage: ElectricPotential::new::<volt>(480.0),
+            working_distance: cubicle.d,
+            ppe: &ppe,
+        };
+        let svg = LabelTemplate::default()
+            .render_result_svg(governing.result.as_ref(), &info)
+            .unwrap();
+        assert!(svg.contains("Arc flash boundary"));
+    }
+
+    let lee = evaluate_method(methods[1].as_ref(), i_bf, &device, None).unwrap();
+    assert_eq!(
+        lee.governing().result.diagnostics(),
+        vec![Warning::LeeMethod]
+    );
+    let dc = evaluate_method(methods[2].as_ref(), i_bf, &device, None).unwrap();
+    assert_eq!(dc.governing().result.diagnostics(), vec![Warning::DcArc]);
+}
+
+/// The label for the governing case matches the label rendered from the 2018 result directly.
+#[test]
+fn test_method_label() {
+    let cubicle = d2_cubicle();
+    let i_bf = ElectricCurrent::new::<kiloampere>(45.0);
+    let device = DefiniteTimeElement {
+        pickup: ElectricCurrent::new::<ampere>(100.0),
+        delay: Time::new::<millisecond>(319.0),
+    };
+    let worst = evaluate_worst_case(&cubicle, i_bf, &device).unwrap();
+    let method = Ieee2018 { cubicle: &cubicle };
+    let evaluation = evaluate_method(&method, i_bf, &device, None).unwrap();
+
+    let ppe = PpeTable::nfpa_70e();
+    let info = LabelInfo {
+        equipment: "MCC-1",
+        nominal_voltage: ElectricPotential::new::<volt>(480.0),
+        working_distance: cubicle.d,
+        ppe: &ppe,
+    };
+    let template = LabelTemplate::default();
+    assert_eq!(
+        template
+            .render_result_svg(evaluation.governing().result.as_ref(), &info)
+            .unwrap(),
+        template
+            .render_svg(&worst.governing().e_afb, &info)
+            .unwrap()
+    );
+}
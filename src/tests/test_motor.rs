@@ -0,0 +1,84 @@
+use super::d2_cubicle;
+use crate::{
+    motor_contribution_profile, multistep_profile, FaultCurrentProfile, InductionMotor, MotorRating,
+};
+use float_cmp::assert_approx_eq;
+use uom::si::electric_current::{ampere, kiloampere, ElectricCurrent};
+use uom::si::electric_potential::{volt, ElectricPotential};
+use uom::si::f64::Frequency;
+use uom::si::frequency::hertz;
+use uom::si::time::{millisecond, Time};
+
+fn motor() -> InductionMotor {
+    InductionMotor {
+        rating: MotorRating::Hp(500.0),
+        v: ElectricPotential::new::<volt>(480.0),
+        x_subtransient: 0.167,
+        x_r: 10.0,
+        frequency: Frequency::new::<hertz>(60.0),
+    }
+}
+
+#[test]
+fn test_motor_decay() {
+    let motor = motor();
+
+    // 500 kVA / (√3 × 480 V) = 601.4 A
+    assert_approx_eq!(f64, motor.i_rated().get::<ampere>(), 601.4, epsilon = 0.1);
+    assert_approx_eq!(
+        f64,
+        motor.i_subtransient().get::<ampere>(),
+        601.4 / 0.167,
+        epsilon = 1.0
+    );
+    // 10 / (2π × 60 Hz) = 26.5 ms
+    assert_approx_eq!(
+        f64,
+        motor.time_constant().get::<millisecond>(),
+        26.526,
+        epsilon = 1e-3
+    );
+    assert_approx_eq!(
+        f64,
+        motor.current(motor.time_constant()).get::<ampere>(),
+        motor.i_subtransient().get::<ampere>() / std::f64::consts::E,
+        epsilon = 1e-6
+    );
+    assert_eq!(
+        motor.current(Time::new::<millisecond>(-1.0)),
+        motor.i_subtransient()
+    );
+}
+
+/// Low voltage calculation example, from Annex D.2, with motor contribution.
+#[test]
+fn test_motor_contribution() {
+    let cubicle = d2_cubicle();
+    let i_bf = ElectricCurrent::new::<kiloampere>(45.0);
+    let t = Time::new::<millisecond>(319.0);
+    let step = Time::new::<millisecond>(5.0);
+
+    let motors = [motor(), motor()];
+    let profile = motor_contribution_profile(i_bf, &motors, t, step).unwrap();
+
+    let initial = profile.i_bf_at(Time::new::<millisecond>(0.0)).unwrap();
+    assert_approx_eq!(
+        f64,
+        initial.get::<ampere>(),
+        (i_bf + 2.0 * motors[0].i_subtransient()).get::<ampere>(),
+        epsilon = 1e-6
+    );
+
+    let with_motors = multistep_profile(&cubicle, &profile, true, t).unwrap();
+    let source_only =
+        multistep_profile(&cubicle, &FaultCurrentProfile::constant(i_bf), true, t).unwrap();
+    let constant_initial =
+        multistep_profile(&cubicle, &FaultCurrentProfile::constant(initial), true, t).unwrap();
+
+    assert!(with_motors.e() > source_only.e());
+    assert!(with_motors.e() < constant_initial.e());
+
+    let mut bad = motor();
+    bad.x_subtransient = 0.0;
+    assert!(motor_contribution_profile(i_bf, &[bad], t, step).is_err());
+}
@@ -0,0 +1,280 @@
+use crate::{
+    Bus, BusId, CubicleBuilder, DefiniteTimeElement, Equipment2002, EquipmentClass, Feeder,
+    Grounding, Ieee2002, Inputs2002, LvTripUnit, NamedDevice, Network, RadialFeed, SeriesElement,
+    ShortTime, Transformer, UtilitySource,
+};
+use float_cmp::assert_approx_eq;
+use uom::si::electric_current::{ampere, kiloampere, ElectricCurrent};
+use uom::si::electric_potential::{kilovolt, volt};
+use uom::si::f64::ElectricPotential;
+use uom::si::length::{meter, Length};
+use uom::si::time::{millisecond, second, Time};
+
+fn source() -> UtilitySource {
+    UtilitySource {
+        v: ElectricPotential::new::<kilovolt>(13.8),
+        mva_sc: 500.0,
+        x_r: 15.0,
+    }
+}
+
+fn transformer() -> SeriesElement {
+    SeriesElement::Transformer(Transformer {
+        kva: 1500.0,
+        v_primary: ElectricPotential::new::<kilovolt>(13.8),
+        v_secondary: ElectricPotential::new::<volt>(480.0),
+        z_percent: 5.75,
+        x_r: 6.0,
+    })
+}
+
+fn feeder(length: f64) -> SeriesElement {
+    SeriesElement::Feeder(Feeder {
+        length: Length::new::<meter>(length),
+        r_ohm_per_km: 0.1,
+        x_ohm_per_km: 0.1,
+        sets: 4,
+    })
+}
+
+fn bus(
+    name: &str,
+    parent: Option<BusId>,
+    connection: Vec<SeriesElement>,
+    class: EquipmentClass,
+    v: ElectricPotential,
+    device: Option<NamedDevice>,
+) -> Bus {
+    Bus {
+        name: name.to_string(),
+        parent,
+        connection,
+        cubicle: CubicleBuilder::new(class, v).build().unwrap(),
+        device,
+    }
+}
+
+/// Medium voltage switchgear, supplying a LV switchboard through a transformer, supplying a MCC.
+fn network() -> (Network, [BusId; 3]) {
+    let mut network = Network::new(source());
+
+    let relay = NamedDevice {
+        name: "52-1".to_string(),
+        device: Box::new(DefiniteTimeElement {
+            pickup: ElectricCurrent::new::<ampere>(200.0),
+            delay: Time::new::<millisecond>(500.0),
+        }),
+    };
+    let swgr = network
+        .add_bus(bus(
+            "SWGR-1",
+            None,
+            vec![],
+            EquipmentClass::Switchgear15kV,
+            ElectricPotential::new::<kilovolt>(13.8),
+            Some(relay),
+        ))
+        .unwrap();
+
+    let mut main = LvTripUnit::new(
+        ElectricCurrent::new::<ampere>(2000.0),
+        Time::new::<second>(10.0),
+    );
+    main.short_time = Some(ShortTime {
+        pickup: ElectricCurrent::new::<kiloampere>(6.0),
+        delay: Time::new::<millisecond>(300.0),
+    });
+    let swbd = network
+        .add_bus(bus(
+            "SWBD-1",
+            Some(swgr),
+            vec![transformer(), feeder(10.0)],
+            EquipmentClass::LvSwitchgear,
+            ElectricPotential::new::<volt>(480.0),
+            Some(NamedDevice {
+                name: "Main".to_string(),
+                device: Box::new(main),
+            }),
+        ))
+        .unwrap();
+
+    let mut breaker = LvTripUnit::new(
+        ElectricCurrent::new::<ampere>(400.0),
+        Time::new::<second>(10.0),
+    );
+    breaker.instantaneous_pickup = Some(ElectricCurrent::new::<kiloampere>(4.0));
+    let mcc = network
+        .add_bus(bus(
+            "MCC-1",
+            Some(swbd),
+            vec![feeder(50.0)],
+            EquipmentClass::LvMcc,
+            ElectricPotential::new::<volt>(480.0),
+            Some(NamedDevice {
+                name: "MCC-1 feeder".to_string(),
+                device: Box::new(breaker),
+            }),
+        ))
+        .unwrap();
+
+    (network, [swgr, swbd, mcc])
+}
+
+/// The ids of one network are not buses of another, even one with the same buses.
+#[test]
+fn test_network_foreign_bus() {
+    let (first, _) = network();
+    let (other, [_, _, mcc]) = network();
+    assert!(first.bus(mcc).is_none());
+    assert!(first.path(mcc).is_err());
+    assert!(first.voltage(mcc).is_err());
+    assert!(first.short_circuit(mcc).is_err());
+    assert_eq!(other.bus(mcc).unwrap().name, "MCC-1");
+}
+
+#[test]
+fn test_network_short_circuit() {
+    let (network, [swgr, swbd, mcc]) = network();
+    assert_eq!(network.path(mcc).unwrap(), vec![swgr, swbd, mcc]);
+    assert_approx_eq!(f64, network.voltage(swgr).unwrap().get::<kilovolt>(), 13.8);
+    assert_approx_eq!(f64, network.voltage(mcc).unwrap().get::<volt>(), 480.0);
+    assert_eq!(network.bus(mcc).unwrap().name, "MCC-1");
+
+    let fault = network.short_circuit(mcc).unwrap();
+    let expected = RadialFeed {
+        source: source(),
+        elements: vec![transformer(), feeder(10.0), feeder(50.0)],
+    }
+    .fault()
+    .unwrap();
+    assert_approx_eq!(
+        f64,
+        fault.i_bf.get::<ampere>(),
+        expected.i_bf.get::<ampere>(),
+        epsilon = 1e-9
+    );
+
+    let mut bad = bus(
+        "Orphan",
+        Some(mcc),
+        vec![],
+        EquipmentClass::LvPanelboard,
+        ElectricPotential::new::<volt>(480.0),
+        None,
+    );
+    let mut other = Network::new(source());
+    assert!(other.add_bus(bad).is_err());
+
+    // A 480 V cubicle on the 13.8 kV bus.
+    bad = bus(
+        "Wrong voltage",
+        None,
+        vec![],
+        EquipmentClass::LvPanelboard,
+        ElectricPotential::new::<volt>(480.0),
+        None,
+    );
+    other.add_bus(bad).unwrap();
+    let study = other.study();
+    assert!(study.buses[0].short_circuit.is_err());
+    assert!(study.buses[0].load_side.is_err());
+}
+
+#[test]
+fn test_network_study() {
+    let (mut network, _) = network();
+    let study = network.study();
+
+    let [swgr, swbd, mcc] = &study.buses[..] else {
+        panic!("expected 3 buses");
+    };
+
+    // Faults at the MCC are cleared by its feeder breaker's instantaneous band.
+    let fault = mcc.load_side.as_ref().unwrap();
+    assert_eq!(fault.device.as_deref(), Some("MCC-1 feeder"));
+    assert_approx_eq!(
+        f64,
+        fault
+            .evaluation
+            .governing()
+            .result
+            .t_arc()
+            .get::<millisecond>(),
+        50.0
+    );
+    // On the line side of the feeder breaker, the switchboard main clears with its short-time band.
+    let fault = mcc.line_side.as_ref().unwrap().as_ref().unwrap();
+    assert_eq!(fault.device.as_deref(), Some("Main"));
+    assert_approx_eq!(
+        f64,
+        fault
+            .evaluation
+            .governing()
+            .result
+            .t_arc()
+            .get::<millisecond>(),
+        300.0
+    );
+
+    let fault = swbd.load_side.as_ref().unwrap();
+    assert_eq!(fault.device.as_deref(), Some("Main"));
+    // On the line side of the main, the MV relay clears, seeing the current through the transformer.
+    let line_side = swbd.line_side.as_ref().unwrap().as_ref().unwrap();
+    assert_eq!(line_side.device.as_deref(), Some("52-1"));
+    assert_approx_eq!(
+        f64,
+        line_side
+            .evaluation
+            .governing()
+            .result
+            .t_arc()
+            .get::<millisecond>(),
+        500.0
+    );
+    assert!(line_side.evaluation.governing().result.e() > fault.evaluation.governing().result.e());
+
+    assert_eq!(
+        swgr.load_side.as_ref().unwrap().device.as_deref(),
+        Some("52-1")
+    );
+    // Nothing upstream of the MV relay.
+    assert!(swgr.line_side.as_ref().unwrap().is_err());
+
+    network.max_arc_duration = Some(Time::new::<second>(2.0));
+    let study = network.study();
+    let fault = study.buses[0].line_side.as_ref().unwrap().as_ref().unwrap();
+    assert_eq!(fault.device, None);
+    assert!(fault.evaluation.governing().capped);
+}
+
+/// The same network by IEEE 1584-2002: the devices and clearing times are found in the same way.
+#[test]
+fn test_network_study_with_method() {
+    let (network, _) = network();
+    let study_2018 = network.study();
+    let study = network.study_with(&|c| {
+        Box::new(Ieee2002 {
+            inputs: Inputs2002::from_cubicle(c, Equipment2002::MccPanel, Grounding::Grounded),
+            device_equation: None,
+        })
+    });
+
+    let fault = study.buses[2].load_side.as_ref().unwrap();
+    assert_eq!(fault.evaluation.method, "IEEE 1584-2002");
+    assert_eq!(fault.device.as_deref(), Some("MCC-1 feeder"));
+    assert_approx_eq!(
+        f64,
+        fault
+            .evaluation
+            .governing()
+            .result
+            .t_arc()
+            .get::<millisecond>(),
+        50.0
+    );
+    let fault_2018 = study_2018.buses[2].load_side.as_ref().unwrap();
+    assert_eq!(fault_2018.evaluation.method, "IEEE 1584-2018");
+    assert!(
+        fault.evaluation.governing().result.e() != fault_2018.evaluation.governing().result.e()
+    );
+}
@@ -0,0 +1,61 @@
+use super::d2_cubicle;
+use crate::units::calorie_per_square_centimeter;
+use crate::{e_afb, i_arc, select_ppe, PpeRating, PpeTable};
+use float_cmp::assert_approx_eq;
+use uom::si::electric_current::{kiloampere, ElectricCurrent};
+use uom::si::f64::RadiantExposure;
+use uom::si::radiant_exposure::joule_per_square_centimeter;
+use uom::si::time::{millisecond, Time};
+
+/// Test low voltage calculation example, from Annex D.2.
+#[test]
+fn test_annex_d2_ppe() {
+    let cubicle = d2_cubicle();
+    let i_bf = ElectricCurrent::new::<kiloampere>(45.0);
+
+    let i_arc_min = i_arc(&cubicle, i_bf, true).unwrap();
+    let e_afb_min = e_afb(&cubicle, i_arc_min, Time::new::<millisecond>(319.0));
+
+    // D.103: 53.156 J/cm² = 12.7 cal/cm²
+    let ppe = select_ppe(&e_afb_min);
+    assert_approx_eq!(
+        f64,
+        ppe.min_arc_rating().get::<joule_per_square_centimeter>(),
+        53.156,
+        epsilon = 1e-3
+    );
+    assert_eq!(ppe.ppe.as_ref().unwrap().name, "PPE Category 3");
+    assert!(ppe.arc_rated_ppe_required());
+    assert!(!ppe.exceeds_40());
+    assert!(!ppe.no_category());
+}
+
+#[test]
+fn test_ppe_table() {
+    let table = PpeTable::default();
+
+    let ppe = table.select(RadiantExposure::new::<calorie_per_square_centimeter>(1.0));
+    assert!(!ppe.arc_rated_ppe_required());
+    assert_eq!(ppe.ppe.unwrap().name, "PPE Category 1");
+
+    let ppe = table.select(RadiantExposure::new::<calorie_per_square_centimeter>(8.0));
+    assert_eq!(ppe.ppe.unwrap().name, "PPE Category 2");
+
+    let ppe = table.select(RadiantExposure::new::<calorie_per_square_centimeter>(45.0));
+    assert!(ppe.exceeds_40());
+    assert!(ppe.no_category());
+
+    let site = PpeTable::new(vec![
+        PpeRating::new(
+            "100 cal suit",
+            RadiantExposure::new::<calorie_per_square_centimeter>(100.0),
+        ),
+        PpeRating::new(
+            "12 cal coverall",
+            RadiantExposure::new::<calorie_per_square_centimeter>(12.0),
+        ),
+    ]);
+    let ppe = site.select(RadiantExposure::new::<calorie_per_square_centimeter>(45.0));
+    assert!(ppe.exceeds_40());
+    assert_eq!(ppe.ppe.unwrap().name, "100 cal suit");
+}
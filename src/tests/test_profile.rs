@@ -0,0 +1,216 @@
+use super::d1_cubicle;
+use crate::{
+    e_afb, i_arc, multistep_profile, profile_clearing_time, DefiniteTimeElement,
+    FaultCurrentProfile, ProfileSample, ProfileSegment, Warning,
+};
+use float_cmp::assert_approx_eq;
+use uom::si::electric_current::{kiloampere, ElectricCurrent};
+use uom::si::radiant_exposure::joule_per_square_centimeter;
+use uom::si::time::{millisecond, second, Time};
+
+/// Test high voltage calculation example, from Annex D.1, with a constant fault current profile.
+#[test]
+fn test_profile_constant() {
+    let cubicle = d1_cubicle();
+    let ka = ElectricCurrent::new::<kiloampere>;
+    let ms = Time::new::<millisecond>;
+
+    let profile = FaultCurrentProfile::constant(ka(15.0));
+    let result = multistep_profile(&cubicle, &profile, false, ms(197.0)).unwrap();
+    assert_eq!(result.steps.len(), 1);
+    // D.32
+    assert_approx_eq!(
+        f64,
+        result.e().get::<joule_per_square_centimeter>(),
+        12.152,
+        epsilon = 1e-3
+    );
+
+    // Sampled at 10 ms, the same current gives the same result.
+    let profile = FaultCurrentProfile::sample(ms(197.0), ms(10.0), |_| ka(15.0)).unwrap();
+    let result = multistep_profile(&cubicle, &profile, false, ms(197.0)).unwrap();
+    assert_eq!(result.steps.len(), 20);
+    assert_approx_eq!(
+        f64,
+        result.e().get::<joule_per_square_centimeter>(),
+        12.152,
+        epsilon = 1e-3
+    );
+    assert_approx_eq!(
+        f64,
+        result.total.t_arc().get::<millisecond>(),
+        197.0,
+        epsilon = 1e-9
+    );
+    assert_approx_eq!(
+        f64,
+        result.steps[19].start.get::<millisecond>(),
+        190.0,
+        epsilon = 1e-9
+    );
+}
+
+#[test]
+fn test_profile_segments() {
+    let cubicle = d1_cubicle();
+    let ka = ElectricCurrent::new::<kiloampere>;
+    let ms = Time::new::<millisecond>;
+
+    // 20 kA for 50 ms, 15 kA for 100 ms, then the upstream device opens.
+    let profile = FaultCurrentProfile::Segments(vec![
+        ProfileSegment {
+            i_bf: ka(20.0),
+            duration: ms(50.0),
+        },
+        ProfileSegment {
+            i_bf: ka(15.0),
+            duration: ms(100.0),
+        },
+        ProfileSegment {
+            i_bf: ka(0.0),
+            duration: ms(0.0),
+        },
+    ]);
+    let result = multistep_profile(&cubicle, &profile, true, ms(500.0)).unwrap();
+    assert_eq!(result.steps.len(), 2);
+    assert!(result.steps.iter().all(|step| step.i_arc.reduced()));
+
+    let e = |i_bf: f64, t: f64| {
+        let i_arc = i_arc(&cubicle, ka(i_bf), true).unwrap();
+        e_afb(&cubicle, i_arc, ms(t)).e()
+    };
+    assert_approx_eq!(
+        f64,
+        result.e().get::<joule_per_square_centimeter>(),
+        (e(20.0, 50.0) + e(15.0, 100.0)).get::<joule_per_square_centimeter>(),
+        epsilon = 1e-9
+    );
+
+    // Cleared before the upstream device opens.
+    let result = multistep_profile(&cubicle, &profile, true, ms(80.0)).unwrap();
+    assert_eq!(result.steps.len(), 2);
+    assert_approx_eq!(
+        f64,
+        result.steps[1].e_afb.t_arc().get::<millisecond>(),
+        30.0,
+        epsilon = 1e-9
+    );
+}
+
+#[test]
+fn test_profile_sampled() {
+    let cubicle = d1_cubicle();
+    let ka = ElectricCurrent::new::<kiloampere>;
+    let ms = Time::new::<millisecond>;
+
+    // Decaying from 20 kA to 10 kA over 100 ms.
+    let profile = FaultCurrentProfile::Sampled {
+        samples: vec![
+            ProfileSample {
+                time: ms(0.0),
+                i_bf: ka(20.0),
+            },
+            ProfileSample {
+                time: ms(100.0),
+                i_bf: ka(10.0),
+            },
+        ],
+        max_step: ms(25.0),
+    };
+    assert_approx_eq!(
+        f64,
+        profile.i_bf_at(ms(50.0)).unwrap().get::<kiloampere>(),
+        15.0,
+        epsilon = 1e-9
+    );
+
+    let result = multistep_profile(&cubicle, &profile, false, ms(150.0)).unwrap();
+    let i_bf: Vec<f64> = result
+        .steps
+        .iter()
+        .map(|step| step.i_arc.i_bf().get::<kiloampere>())
+        .collect();
+    assert_eq!(i_bf.len(), 6);
+    for (i, expected) in i_bf.iter().zip([18.75, 16.25, 13.75, 11.25, 10.0, 10.0]) {
+        assert_approx_eq!(f64, *i, expected, epsilon = 1e-9);
+    }
+
+    let profile = FaultCurrentProfile::Sampled {
+        samples: vec![
+            ProfileSample {
+                time: ms(10.0),
+                i_bf: ka(20.0),
+            },
+            ProfileSample {
+                time: ms(10.0),
+                i_bf: ka(10.0),
+            },
+        ],
+        max_step: ms(25.0),
+    };
+    assert!(multistep_profile(&cubicle, &profile, false, ms(150.0)).is_err());
+
+    // Outside the range of the model
+    let profile = FaultCurrentProfile::constant(ka(100.0));
+    assert!(multistep_profile(&cubicle, &profile, false, ms(150.0)).is_err());
+}
+
+/// Steps below the range of the model give no energy, with a warning, and the number of steps is limited.
+#[test]
+fn test_profile_below_range_and_step_limit() {
+    let cubicle = d1_cubicle();
+    let ka = ElectricCurrent::new::<kiloampere>;
+    let ms = Time::new::<millisecond>;
+
+    // 100 mA for 50 ms (below the 200 A minimum for HV), then 15 kA.
+    let profile = FaultCurrentProfile::Segments(vec![
+        ProfileSegment {
+            i_bf: ka(0.1),
+            duration: ms(50.0),
+        },
+        ProfileSegment {
+            i_bf: ka(15.0),
+            duration: ms(0.0),
+        },
+    ]);
+    let result = multistep_profile(&cubicle, &profile, false, ms(247.0)).unwrap();
+    assert_eq!(result.steps.len(), 1);
+    let constant = multistep_profile(
+        &cubicle,
+        &FaultCurrentProfile::constant(ka(15.0)),
+        false,
+        ms(197.0),
+    )
+    .unwrap();
+    assert_approx_eq!(f64, result.e().value, constant.e().value, epsilon = 1e-9);
+    assert_eq!(
+        result.warnings,
+        vec![Warning::ProfileBelowRange {
+            i_bf: ka(0.1),
+            duration: ms(50.0)
+        }]
+    );
+    assert!(constant.warnings.is_empty());
+
+    let device = DefiniteTimeElement {
+        pickup: ka(0.0),
+        delay: ms(100.0),
+    };
+    let t = profile_clearing_time(&cubicle, &profile, false, &device, ms(2000.0)).unwrap();
+    assert_approx_eq!(f64, t.unwrap().get::<millisecond>(), 150.0, epsilon = 1e-6);
+
+    // 10^7 steps of 1 µs.
+    assert!(
+        FaultCurrentProfile::sample(Time::new::<second>(10.0), ms(1e-3), |_| ka(15.0)).is_err()
+    );
+    let profile = FaultCurrentProfile::Sampled {
+        samples: vec![ProfileSample {
+            time: ms(0.0),
+            i_bf: ka(15.0),
+        }],
+        max_step: ms(1e-3),
+    };
+    let err = profile.segments(Time::new::<second>(10.0)).unwrap_err();
+    assert!(err.to_string().contains("more than 100000 steps"));
+    assert!(multistep_profile(&cubicle, &profile, false, Time::new::<second>(10.0)).is_err());
+}
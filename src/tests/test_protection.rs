@@ -0,0 +1,112 @@
+use super::d1_cubicle;
+use crate::{
+    e_afb_with_device, DefiniteTimeElement, InverseCurve, InverseTimeElement, LvTripUnit,
+    ProtectiveDevice, RelayedBreaker, ShortTime,
+};
+use float_cmp::assert_approx_eq;
+use uom::si::electric_current::{ampere, kiloampere, ElectricCurrent};
+use uom::si::radiant_exposure::joule_per_square_centimeter;
+use uom::si::time::{millisecond, second, Time};
+
+#[test]
+fn test_inverse_curves() {
+    let relay = InverseTimeElement {
+        curve: InverseCurve::IecStandardInverse,
+        pickup: ElectricCurrent::new::<ampere>(1000.0),
+        time_dial: 0.1,
+    };
+    // 0.1 * 0.14 / (10^0.02 - 1)
+    let t = relay
+        .clearing_time(ElectricCurrent::new::<kiloampere>(10.0))
+        .unwrap();
+    assert_approx_eq!(f64, t.get::<second>(), 0.2971, epsilon = 1e-4);
+
+    let relay = InverseTimeElement {
+        curve: InverseCurve::IeeeVeryInverse,
+        pickup: ElectricCurrent::new::<ampere>(1000.0),
+        time_dial: 2.0,
+    };
+    // 2 * (19.61 / (5^2 - 1) + 0.491)
+    let t = relay
+        .clearing_time(ElectricCurrent::new::<kiloampere>(5.0))
+        .unwrap();
+    assert_approx_eq!(f64, t.get::<second>(), 2.6162, epsilon = 1e-4);
+
+    // Below pickup.
+    assert!(relay
+        .clearing_time(ElectricCurrent::new::<ampere>(900.0))
+        .is_none());
+}
+
+#[test]
+fn test_lv_trip_unit() {
+    let mut trip = LvTripUnit::new(
+        ElectricCurrent::new::<ampere>(800.0),
+        Time::new::<second>(10.0),
+    );
+    trip.short_time = Some(ShortTime {
+        pickup: ElectricCurrent::new::<ampere>(4000.0),
+        delay: Time::new::<millisecond>(300.0),
+    });
+    trip.instantaneous_pickup = Some(ElectricCurrent::new::<ampere>(12000.0));
+
+    // Long-time band, 2 x LTPU: 10 s * (6 / 2)^2
+    let t = trip
+        .clearing_time(ElectricCurrent::new::<ampere>(1600.0))
+        .unwrap();
+    assert_approx_eq!(f64, t.get::<second>(), 90.0, epsilon = 1e-9);
+
+    // Short-time band
+    let t = trip
+        .clearing_time(ElectricCurrent::new::<ampere>(8000.0))
+        .unwrap();
+    assert_approx_eq!(f64, t.get::<millisecond>(), 300.0, epsilon = 1e-9);
+
+    // Instantaneous band
+    let t = trip
+        .clearing_time(ElectricCurrent::new::<kiloampere>(20.0))
+        .unwrap();
+    assert_approx_eq!(f64, t.get::<millisecond>(), 50.0, epsilon = 1e-9);
+
+    assert!(trip
+        .clearing_time(ElectricCurrent::new::<ampere>(700.0))
+        .is_none());
+}
+
+/// Test high voltage calculation example, from Annex D.1, with the clearing time from a relay.
+#[test]
+fn test_annex_d1_with_device() {
+    let cubicle = d1_cubicle();
+    let i_bf = ElectricCurrent::new::<kiloampere>(15.0);
+
+    let device = RelayedBreaker {
+        elements: vec![Box::new(DefiniteTimeElement {
+            pickup: ElectricCurrent::new::<kiloampere>(5.0),
+            delay: Time::new::<millisecond>(147.0),
+        })],
+        opening_time: Time::new::<millisecond>(50.0),
+    };
+
+    let (i_arc_max, e_afb_max) = e_afb_with_device(&cubicle, i_bf, false, &device).unwrap();
+
+    // D.17
+    assert_approx_eq!(
+        f64,
+        i_arc_max.i_arc().get::<kiloampere>(),
+        12.979,
+        epsilon = 1e-3
+    );
+    // D.32
+    assert_approx_eq!(
+        f64,
+        e_afb_max.e().get::<joule_per_square_centimeter>(),
+        12.152,
+        epsilon = 1e-3
+    );
+
+    let device = DefiniteTimeElement {
+        pickup: ElectricCurrent::new::<kiloampere>(20.0),
+        delay: Time::new::<millisecond>(100.0),
+    };
+    assert!(e_afb_with_device(&cubicle, i_bf, false, &device).is_err());
+}
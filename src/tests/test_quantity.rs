@@ -0,0 +1,60 @@
+use crate::quantity::{parse_box, parse_current, parse_length, parse_time, parse_voltage};
+use crate::ElectrodeConfiguration;
+use float_cmp::assert_approx_eq;
+use uom::si::electric_current::ampere;
+use uom::si::electric_potential::volt;
+use uom::si::length::millimeter;
+use uom::si::time::millisecond;
+
+#[test]
+fn test_parse_quantities() {
+    assert_approx_eq!(f64, parse_voltage("4.16kV").unwrap().get::<volt>(), 4160.0);
+    assert_approx_eq!(f64, parse_voltage("480 V").unwrap().get::<volt>(), 480.0);
+    assert_approx_eq!(f64, parse_current("15kA").unwrap().get::<ampere>(), 15000.0);
+    assert_approx_eq!(f64, parse_current("800A").unwrap().get::<ampere>(), 800.0);
+    assert_approx_eq!(
+        f64,
+        parse_length("914.4mm").unwrap().get::<millimeter>(),
+        914.4,
+        epsilon = 1e-9
+    );
+    assert_approx_eq!(
+        f64,
+        parse_length("1.5m").unwrap().get::<millimeter>(),
+        1500.0,
+        epsilon = 1e-9
+    );
+    assert_approx_eq!(
+        f64,
+        parse_length("36in").unwrap().get::<millimeter>(),
+        914.4,
+        epsilon = 1e-9
+    );
+    assert_approx_eq!(
+        f64,
+        parse_time("197ms").unwrap().get::<millisecond>(),
+        197.0,
+        epsilon = 1e-9
+    );
+    assert_approx_eq!(
+        f64,
+        parse_time("2s").unwrap().get::<millisecond>(),
+        2000.0,
+        epsilon = 1e-9
+    );
+
+    let dim = parse_box("1143x762x508mm").unwrap();
+    assert_approx_eq!(f64, dim.height.get::<millimeter>(), 1143.0, epsilon = 1e-9);
+    assert_approx_eq!(f64, dim.width.get::<millimeter>(), 762.0, epsilon = 1e-9);
+    assert_approx_eq!(f64, dim.depth.get::<millimeter>(), 508.0, epsilon = 1e-9);
+
+    assert_eq!(
+        "vcbb".parse::<ElectrodeConfiguration>().unwrap(),
+        ElectrodeConfiguration::VCBB
+    );
+
+    assert!(parse_voltage("4.16").is_err());
+    assert!(parse_length("104 furlongs").is_err());
+    assert!(parse_box("1143x762mm").is_err());
+    assert!("XYZ".parse::<ElectrodeConfiguration>().is_err());
+}
@@ -0,0 +1,139 @@
+use super::{d1_cubicle, d2_cubicle};
+use crate::{
+    evaluate_worst_case, ApproachDistance, ArcingCurrentCase, DefiniteTimeElement,
+    FixedClearingTimes, Ieee2018, Scenario,
+};
+use float_cmp::assert_approx_eq;
+use uom::si::electric_current::{kiloampere, ElectricCurrent};
+use uom::si::length::{meter, Length};
+use uom::si::radiant_exposure::joule_per_square_centimeter;
+use uom::si::time::{millisecond, second, Time};
+
+/// Test high voltage calculation example, from Annex D.1.
+#[test]
+fn test_annex_d1_worst_case() {
+    let cubicle = d1_cubicle();
+    let i_bf = ElectricCurrent::new::<kiloampere>(15.0);
+    let clearing = FixedClearingTimes {
+        full: Time::new::<millisecond>(197.0),
+        reduced: Time::new::<millisecond>(223.0),
+    };
+
+    let worst = evaluate_worst_case(&cubicle, i_bf, &clearing).unwrap();
+
+    // D.32
+    assert_approx_eq!(
+        f64,
+        worst.full.e_afb.e().get::<joule_per_square_centimeter>(),
+        12.152,
+        epsilon = 1e-3
+    );
+    // D.62
+    assert_approx_eq!(
+        f64,
+        worst.reduced.e_afb.e().get::<joule_per_square_centimeter>(),
+        13.343,
+        epsilon = 1e-3
+    );
+    assert_eq!(worst.governing, ArcingCurrentCase::Reduced);
+    assert!(worst.governing().i_arc.reduced());
+
+    assert_eq!(
+        worst.approach.limited_fixed,
+        ApproachDistance::Distance(Length::new::<meter>(1.5))
+    );
+    assert_eq!(
+        worst.approach.restricted,
+        ApproachDistance::Distance(Length::new::<meter>(0.7))
+    );
+}
+
+/// Test low voltage calculation example, from Annex D.2.
+#[test]
+fn test_annex_d2_worst_case() {
+    let cubicle = d2_cubicle();
+    let i_bf = ElectricCurrent::new::<kiloampere>(45.0);
+    let clearing = FixedClearingTimes {
+        full: Time::new::<millisecond>(61.3),
+        reduced: Time::new::<millisecond>(319.0),
+    };
+
+    let worst = evaluate_worst_case(&cubicle, i_bf, &clearing).unwrap();
+
+    // D.103
+    assert_approx_eq!(
+        f64,
+        worst
+            .governing()
+            .e_afb
+            .e()
+            .get::<joule_per_square_centimeter>(),
+        53.156,
+        epsilon = 1e-3
+    );
+    assert_eq!(worst.governing, ArcingCurrentCase::Reduced);
+
+    // Device that only operates on the full arcing current (28.793 kA), not the reduced current (25.244 kA).
+    let device = DefiniteTimeElement {
+        pickup: ElectricCurrent::new::<kiloampere>(27.0),
+        delay: Time::new::<millisecond>(61.3),
+    };
+    assert!(evaluate_worst_case(&cubicle, i_bf, &device).is_err());
+
+    let mut scenario = Scenario::new(&cubicle, i_bf);
+    scenario.max_arc_duration = Some(Time::new::<second>(2.0));
+    let worst = scenario.evaluate_worst_case(&device).unwrap();
+    assert!(!worst.full.capped);
+    assert!(worst.reduced.capped);
+    assert_approx_eq!(
+        f64,
+        worst.reduced.e_afb.t_arc().get::<second>(),
+        2.0,
+        epsilon = 1e-9
+    );
+    assert_eq!(worst.governing, ArcingCurrentCase::Reduced);
+
+    // The same scenario through the method trait caps the same case.
+    let evaluation = scenario
+        .evaluate_method(&Ieee2018 { cubicle: &cubicle }, &device)
+        .unwrap();
+    let reduced = evaluation.case(ArcingCurrentCase::Reduced).unwrap();
+    assert!(reduced.capped);
+    assert!(!evaluation.case(ArcingCurrentCase::Full).unwrap().capped);
+    assert_eq!(reduced.result.e(), worst.reduced.e_afb.e());
+    assert_eq!(evaluation.governing().case, ArcingCurrentCase::Reduced);
+}
+
+/// The capped marker is on the case header and every line of each case is indented.
+#[test]
+fn test_worst_case_display() {
+    let cubicle = d2_cubicle();
+    let device = DefiniteTimeElement {
+        pickup: ElectricCurrent::new::<kiloampere>(27.0),
+        delay: Time::new::<millisecond>(61.3),
+    };
+    let mut scenario = Scenario::new(&cubicle, ElectricCurrent::new::<kiloampere>(45.0));
+    scenario.max_arc_duration = Some(Time::new::<second>(2.0));
+    let report = scenario.evaluate_worst_case(&device).unwrap().to_string();
+
+    let lines: Vec<&str> = report.lines().collect();
+    assert_eq!(lines[0], "Full:");
+    let reduced = lines
+        .iter()
+        .position(|l| *l == "Reduced (capped):")
+        .unwrap();
+    let governing = lines
+        .iter()
+        .position(|l| l.starts_with("Governing case: "))
+        .unwrap();
+    assert!(reduced > 2);
+    for line in lines[1..reduced]
+        .iter()
+        .chain(&lines[reduced + 1..governing])
+    {
+        assert!(line.starts_with("    "), "{:?}", line);
+    }
+    assert!(lines[reduced + 1..governing]
+        .iter()
+        .any(|l| l.contains("T_arc = 2000 ms")));
+}
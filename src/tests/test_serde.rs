@@ -0,0 +1,81 @@
+use super::d1_cubicle;
+use crate::{e_afb, i_arc, Cubicle, CubicleData, EAfb, IArc};
+use float_cmp::assert_approx_eq;
+use uom::si::electric_current::{kiloampere, ElectricCurrent};
+use uom::si::radiant_exposure::joule_per_square_centimeter;
+use uom::si::time::{millisecond, Time};
+
+/// Test round trip of the high voltage calculation example, from Annex D.1, through JSON.
+#[test]
+fn test_serde_round_trip() {
+    let cubicle = d1_cubicle();
+
+    let json = serde_json::to_value(&cubicle).unwrap();
+    assert_eq!(json["ec"], "VCB");
+    let field = |v: &serde_json::Value| v.as_f64().unwrap();
+    assert_approx_eq!(f64, field(&json["v_oc_kv"]), 4.16, epsilon = 1e-9);
+    assert_approx_eq!(f64, field(&json["g_mm"]), 104.0, epsilon = 1e-9);
+    assert_approx_eq!(f64, field(&json["d_mm"]), 914.4, epsilon = 1e-9);
+    assert_approx_eq!(
+        f64,
+        field(&json["dim"]["height_mm"]),
+        1143.0,
+        epsilon = 1e-9
+    );
+    assert_approx_eq!(f64, field(&json["dim"]["width_mm"]), 762.0, epsilon = 1e-9);
+    assert_approx_eq!(f64, field(&json["dim"]["depth_mm"]), 508.0, epsilon = 1e-9);
+
+    let cubicle: Cubicle = serde_json::from_value(json).unwrap();
+    // D.14
+    assert_approx_eq!(f64, cubicle.cf, 1.284, epsilon = 1e-3);
+
+    let i_arc_min = i_arc(&cubicle, ElectricCurrent::new::<kiloampere>(15.0), true).unwrap();
+    let json = serde_json::to_string(&i_arc_min).unwrap();
+    assert!(json.contains(r#""range":"high_voltage""#));
+    assert!(json.contains(r#""i_bf_ka":"#));
+    let i_arc_min: IArc = serde_json::from_str(&json).unwrap();
+    assert!(i_arc_min.reduced());
+
+    let e_afb_min = e_afb(&cubicle, i_arc_min, Time::new::<millisecond>(223.0));
+    let json = serde_json::to_string(&e_afb_min).unwrap();
+    assert!(json.contains(r#""t_arc_ms":"#));
+    let e_afb_min: EAfb = serde_json::from_str(&json).unwrap();
+    // D.62
+    assert_approx_eq!(
+        f64,
+        e_afb_min.e().get::<joule_per_square_centimeter>(),
+        13.343,
+        epsilon = 1e-3
+    );
+}
+
+/// Deserialized cubicles are checked against the range of the model.
+#[test]
+fn test_serde_validation() {
+    let json = r#"{
+        "v_oc_kv": 4.16,
+        "ec": "VCB",
+        "g_mm": 10.0,
+        "d_mm": 914.4,
+        "dim": {"height_mm": 1143.0, "width_mm": 762.0, "depth_mm": 508.0}
+    }"#;
+    let err = serde_json::from_str::<Cubicle>(json).err().unwrap();
+    assert!(err.to_string().contains("busbar gap G must be >= 19.05 mm"));
+    let data: CubicleData = serde_json::from_str(json).unwrap();
+    let err = Cubicle::try_from(data).err().unwrap();
+    assert_eq!(err.field(), "g");
+
+    let json = json.replace(r#""VCB""#, r#""XYZ""#);
+    assert!(serde_json::from_str::<Cubicle>(&json).is_err());
+
+    let json = r#"{
+        "v_oc_kv": 4.16,
+        "ec": "VCB",
+        "g_mm": 104.0,
+        "d_mm": 914.4,
+        "dim": {"height_mm": 1143.0, "width_mm": 762.0, "depth_mm": 508.0},
+        "frequency_hz": 400.0
+    }"#;
+    let err = serde_json::from_str::<Cubicle>(json).err().unwrap();
+    assert!(err.to_string().contains("frequency 400 Hz"));
+}
@@ -0,0 +1,194 @@
+use crate::{
+    ConductorMaterial, ConductorSize, Conduit, Feeder, RadialFeed, SeriesElement, ShortCircuit,
+    Transformer, UtilitySource,
+};
+use float_cmp::assert_approx_eq;
+use uom::si::electric_current::{ampere, kiloampere};
+use uom::si::electric_potential::{kilovolt, volt, ElectricPotential};
+use uom::si::length::{meter, Length};
+
+fn source(mva_sc: f64) -> UtilitySource {
+    UtilitySource {
+        v: ElectricPotential::new::<kilovolt>(13.8),
+        mva_sc,
+        x_r: 15.0,
+    }
+}
+
+fn transformer() -> Transformer {
+    Transformer {
+        kva: 1500.0,
+        v_primary: ElectricPotential::new::<kilovolt>(13.8),
+        v_secondary: ElectricPotential::new::<volt>(480.0),
+        z_percent: 5.75,
+        x_r: 6.0,
+    }
+}
+
+#[test]
+fn test_infinite_bus() {
+    let feed = RadialFeed {
+        source: source(f64::INFINITY),
+        elements: vec![SeriesElement::Transformer(transformer())],
+    };
+    let fault = feed.fault().unwrap();
+
+    // Full load current / Z: 1500 kVA / (√3 × 480 V) / 0.0575 = 31.38 kA
+    let i_fl = 1500e3 / (3.0_f64.sqrt() * 480.0);
+    assert_approx_eq!(
+        f64,
+        fault.i_bf.get::<ampere>(),
+        i_fl / 0.0575,
+        epsilon = 1e-6
+    );
+    assert_approx_eq!(f64, fault.x_r(), 6.0, epsilon = 1e-9);
+    assert_approx_eq!(f64, fault.v.get::<volt>(), 480.0);
+}
+
+#[test]
+fn test_radial_feed() {
+    // 500 MVA at 13.8 kV, 1500 kVA transformer, then 30 m of 2 sets of cable.
+    let feeder = Feeder {
+        length: Length::new::<meter>(30.0),
+        r_ohm_per_km: 0.2,
+        x_ohm_per_km: 0.15,
+        sets: 2,
+    };
+    let feed = RadialFeed {
+        source: source(500.0),
+        elements: vec![
+            SeriesElement::Transformer(transformer()),
+            SeriesElement::Feeder(feeder),
+        ],
+    };
+    let fault = feed.fault().unwrap();
+
+    // Per unit on 100 MVA.
+    let z_s = 100.0 / 500.0;
+    let z_t = 0.0575 * 100.0 / 1.5;
+    let z_base = 0.48_f64.powi(2) / 100.0;
+    let r = z_s / 226.0_f64.sqrt() + z_t / 37.0_f64.sqrt() + 0.2 * 0.03 / 2.0 / z_base;
+    let x =
+        15.0 * z_s / 226.0_f64.sqrt() + 6.0 * z_t / 37.0_f64.sqrt() + 0.15 * 0.03 / 2.0 / z_base;
+    let i_base = 100e6 / (3.0_f64.sqrt() * 480.0);
+
+    assert_approx_eq!(f64, fault.z.r, r, epsilon = 1e-9);
+    assert_approx_eq!(f64, fault.z.x, x, epsilon = 1e-9);
+    assert_approx_eq!(
+        f64,
+        fault.i_bf.get::<ampere>(),
+        i_base / r.hypot(x),
+        epsilon = 1e-6
+    );
+    // Roughly 22.6 kA, less than the transformer alone.
+    assert!(fault.i_bf.get::<kiloampere>() > 22.0 && fault.i_bf.get::<kiloampere>() < 23.0);
+
+    // Fault at the source bus only.
+    let at_source = RadialFeed {
+        source: source(500.0),
+        elements: vec![],
+    }
+    .fault()
+    .unwrap();
+    assert_approx_eq!(
+        f64,
+        at_source.i_bf.get::<kiloampere>(),
+        500.0 / (3.0_f64.sqrt() * 13.8),
+        epsilon = 1e-9
+    );
+}
+
+#[test]
+fn test_off_nominal_tap() {
+    // A 13.2 kV primary on a 13.8 kV system: the secondary is at 480 × 13.8 / 13.2 = 501.8 V, and the impedance
+    // on the system base is (13.2 / 13.8)² of the nameplate value.
+    let mut tx = transformer();
+    tx.v_primary = ElectricPotential::new::<kilovolt>(13.2);
+    let fault = RadialFeed {
+        source: source(f64::INFINITY),
+        elements: vec![SeriesElement::Transformer(tx)],
+    }
+    .fault()
+    .unwrap();
+
+    let v = 480.0 * 13.8 / 13.2;
+    let z = 0.0575 * 100.0 / 1.5 * (13.2_f64 / 13.8).powi(2);
+    assert_approx_eq!(f64, fault.v.get::<volt>(), v, epsilon = 1e-9);
+    assert_approx_eq!(f64, fault.z.z(), z, epsilon = 1e-12);
+    assert_approx_eq!(
+        f64,
+        fault.i_bf.get::<ampere>(),
+        100e6 / (3.0_f64.sqrt() * v) / z,
+        epsilon = 1e-6
+    );
+    // Higher than at the nominal tap, by the voltage ratio.
+    let nominal = RadialFeed {
+        source: source(f64::INFINITY),
+        elements: vec![SeriesElement::Transformer(transformer())],
+    }
+    .fault()
+    .unwrap();
+    assert_approx_eq!(
+        f64,
+        (fault.i_bf / nominal.i_bf).value,
+        13.8 / 13.2,
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_feeder_from_conductor() {
+    let length = Length::new::<meter>(100.0);
+    // NEC Table 9: 500 kcmil copper in PVC conduit, R = 0.027 and X = 0.039 ohm per 1000 ft.
+    let feeder = Feeder::from_conductor(
+        length,
+        ConductorSize::Kcmil500,
+        ConductorMaterial::Copper,
+        Conduit::Pvc,
+        2,
+    )
+    .unwrap();
+    assert_approx_eq!(f64, feeder.r_ohm_per_km, 0.027 / 0.3048, epsilon = 1e-12);
+    assert_approx_eq!(f64, feeder.x_ohm_per_km, 0.039 / 0.3048, epsilon = 1e-12);
+    assert_eq!(feeder.sets, 2);
+
+    // Steel conduit has the higher reactance; aluminum the higher resistance.
+    let steel = Feeder::from_conductor(
+        length,
+        ConductorSize::Kcmil500,
+        ConductorMaterial::Aluminum,
+        Conduit::Steel,
+        2,
+    )
+    .unwrap();
+    assert_approx_eq!(f64, steel.r_ohm_per_km, 0.045 / 0.3048, epsilon = 1e-12);
+    assert_approx_eq!(f64, steel.x_ohm_per_km, 0.048 / 0.3048, epsilon = 1e-12);
+
+    assert!(Feeder::from_conductor(
+        length,
+        ConductorSize::Awg14,
+        ConductorMaterial::Aluminum,
+        Conduit::Pvc,
+        1
+    )
+    .is_err());
+}
+
+#[test]
+fn test_short_circuit_invalid() {
+    let mut tx = transformer();
+    tx.v_primary = ElectricPotential::new::<kilovolt>(4.16);
+    let feed = RadialFeed {
+        source: source(500.0),
+        elements: vec![SeriesElement::Transformer(tx)],
+    };
+    assert!(feed.fault().is_err());
+
+    let mut tx = transformer();
+    tx.z_percent = 0.0;
+    assert!(tx.impedance().is_err());
+
+    assert!(source(0.0).impedance().is_err());
+    assert!(source(f64::NAN).impedance().is_err());
+    assert!(ShortCircuit::new(ElectricPotential::new::<volt>(480.0), Default::default()).is_err());
+}
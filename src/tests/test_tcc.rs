@@ -0,0 +1,105 @@
+use crate::{capped_clearing_time, ProtectiveDevice, TabulatedCurve};
+use float_cmp::assert_approx_eq;
+use uom::si::electric_current::{ampere, ElectricCurrent};
+use uom::si::time::{second, Time};
+
+const FUSE_CSV: &str = "\
+# 400 A fuse
+current_a, time_s, min_time_s
+1000, 100.0, 50.0
+2000, 10.0, 4.0
+4000, 1.0, 0.3
+10000, 0.01, 0.005
+";
+
+#[test]
+fn test_tcc_csv() {
+    let curve = TabulatedCurve::from_csv(FUSE_CSV.as_bytes()).unwrap();
+    assert_eq!(curve.points().len(), 4);
+    assert_approx_eq!(f64, curve.min_current().get::<ampere>(), 1000.0);
+
+    // On a point
+    let t = curve
+        .clearing_time(ElectricCurrent::new::<ampere>(2000.0))
+        .unwrap();
+    assert_approx_eq!(f64, t.get::<second>(), 10.0, epsilon = 1e-9);
+
+    // Log-log interpolation: sqrt(1000 * 2000) is halfway between 100 s and 10 s on a log scale.
+    let t = curve
+        .clearing_time(ElectricCurrent::new::<ampere>(f64::sqrt(2.0e6)))
+        .unwrap();
+    assert_approx_eq!(f64, t.get::<second>(), f64::sqrt(1000.0), epsilon = 1e-9);
+
+    let t = curve
+        .min_clearing_time(ElectricCurrent::new::<ampere>(4000.0))
+        .unwrap();
+    assert_approx_eq!(f64, t.get::<second>(), 0.3, epsilon = 1e-9);
+
+    // Flat above the curve
+    let t = curve
+        .clearing_time(ElectricCurrent::new::<ampere>(50000.0))
+        .unwrap();
+    assert_approx_eq!(f64, t.get::<second>(), 0.01, epsilon = 1e-9);
+
+    // Does not clear below the curve
+    let i = ElectricCurrent::new::<ampere>(900.0);
+    assert!(curve.clearing_time(i).is_none());
+
+    let (t, capped) = capped_clearing_time(&curve, i, Time::new::<second>(2.0));
+    assert!(capped);
+    assert_approx_eq!(f64, t.get::<second>(), 2.0);
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn test_tcc_json() {
+    let json = r#"{"points": [
+        {"current": 4000.0, "time": 1.0},
+        {"current": 1000.0, "time": 100.0}
+    ]}"#;
+    let curve = TabulatedCurve::from_json(json.as_bytes()).unwrap();
+
+    let t = curve
+        .clearing_time(ElectricCurrent::new::<ampere>(2000.0))
+        .unwrap();
+    assert_approx_eq!(f64, t.get::<second>(), 10.0, epsilon = 1e-9);
+}
+
+#[test]
+fn test_tcc_invalid() {
+    assert!(TabulatedCurve::from_csv("current_a, time_s\n1000, 1.0\n".as_bytes()).is_err());
+    assert!(
+        TabulatedCurve::from_csv("current_a, time_s\n1000, 1.0\n1000, 2.0\n".as_bytes()).is_err()
+    );
+    assert!(
+        TabulatedCurve::from_csv("current_a, time_s\n1000, 1.0\n2000, -2.0\n".as_bytes()).is_err()
+    );
+    assert!(
+        TabulatedCurve::from_csv("current_a, time_s\n1000, 1.0\n2000, x\n".as_bytes()).is_err()
+    );
+
+    // Not finite
+    assert!(
+        TabulatedCurve::from_csv("current_a, time_s\n1000, 1.0\ninf, 0.1\n".as_bytes()).is_err()
+    );
+    assert!(
+        TabulatedCurve::from_csv("current_a, time_s\n1000, inf\n2000, 0.1\n".as_bytes()).is_err()
+    );
+    assert!(TabulatedCurve::from_csv(
+        "current_a, time_s, min_time_s\n1000, 1.0, 0.5\n2000, 0.1, NaN\n".as_bytes()
+    )
+    .is_err());
+
+    // Time increasing with current
+    assert!(
+        TabulatedCurve::from_csv("current_a, time_s\n1000, 1.0\n2000, 2.0\n".as_bytes()).is_err()
+    );
+    assert!(TabulatedCurve::from_csv(
+        "current_a, time_s, min_time_s\n1000, 1.0, 0.2\n2000, 0.5, 0.3\n".as_bytes()
+    )
+    .is_err());
+    // Flat is allowed
+    assert!(
+        TabulatedCurve::from_csv("current_a, time_s\n1000, 0.1\n2000, 0.1\n".as_bytes()).is_ok()
+    );
+}
@@ -0,0 +1,116 @@
+use crate::{
+    e_afb, i_arc, multistep_e_and_afb_with_warnings, multistep_total, Cubicle,
+    ElectrodeConfiguration, FixedClearingTimes, Scenario, Warning,
+};
+use uom::si::electric_current::{kiloampere, ElectricCurrent};
+use uom::si::electric_potential::{kilovolt, volt};
+use uom::si::f64::ElectricPotential;
+use uom::si::length::{millimeter, Length};
+use uom::si::time::{millisecond, second, Time};
+
+fn cubicle(v_oc: ElectricPotential, ec: ElectrodeConfiguration, dim: [f64; 3]) -> Cubicle {
+    Cubicle::new(
+        v_oc,
+        ec,
+        Length::new::<millimeter>(25.0),
+        Length::new::<millimeter>(457.2),
+        Length::new::<millimeter>(dim[0]),
+        Length::new::<millimeter>(dim[1]),
+        Length::new::<millimeter>(dim[2]),
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_cubicle_warnings() {
+    let mm = Length::new::<millimeter>;
+    let v_480 = ElectricPotential::new::<volt>(480.0);
+
+    // Annex D.2 enclosure
+    let c = cubicle(v_480, ElectrodeConfiguration::VCB, [610.0, 610.0, 254.0]);
+    assert!(c.warnings.is_empty());
+
+    // Shallow enclosure
+    let c = cubicle(v_480, ElectrodeConfiguration::VCB, [355.6, 304.8, 190.5]);
+    assert!(c.warnings.is_empty());
+
+    // Height and width of a shallow enclosure, but too deep
+    let c = cubicle(v_480, ElectrodeConfiguration::VCB, [355.6, 304.8, 254.0]);
+    assert_eq!(
+        c.warnings,
+        vec![
+            Warning::ShallowEnclosureDepth { depth: mm(254.0) },
+            Warning::EnclosureSizeIncreased {
+                height: mm(355.6),
+                width: mm(304.8)
+            }
+        ]
+    );
+
+    let c = cubicle(
+        ElectricPotential::new::<volt>(208.0),
+        ElectrodeConfiguration::HCB,
+        [1500.0, 610.0, 254.0],
+    );
+    assert_eq!(
+        c.warnings,
+        vec![
+            Warning::LowVoltage240 {
+                v_oc: ElectricPotential::new::<volt>(208.0)
+            },
+            Warning::EnclosureSizeLimited {
+                height: mm(1500.0),
+                width: mm(610.0)
+            }
+        ]
+    );
+    assert!(c.to_string().contains("Warnings:"));
+
+    // No enclosure size correction for open air
+    let c = cubicle(
+        ElectricPotential::new::<kilovolt>(4.16),
+        ElectrodeConfiguration::VOA,
+        [1500.0, 300.0, 254.0],
+    );
+    assert!(c.warnings.is_empty());
+}
+
+#[test]
+fn test_arc_duration_warnings() {
+    let c = cubicle(
+        ElectricPotential::new::<kilovolt>(4.16),
+        ElectrodeConfiguration::VCB,
+        [1143.0, 762.0, 508.0],
+    );
+    let i_bf = ElectricCurrent::new::<kiloampere>(15.0);
+
+    let i_arc = i_arc(&c, i_bf, false).unwrap();
+    assert!(i_arc.warnings().is_empty());
+
+    let step = || e_afb(&c, i_arc.clone(), Time::new::<millisecond>(1500.0));
+    assert!(step().warnings().is_empty());
+
+    let total = multistep_total(&c, &[step(), step()]).unwrap();
+    assert_eq!(
+        total.warnings(),
+        vec![Warning::LongArcDuration {
+            t_arc: Time::new::<second>(3.0)
+        }]
+    );
+    assert_eq!(
+        multistep_e_and_afb_with_warnings(&c, &[step(), step()])
+            .unwrap()
+            .2,
+        total.warnings()
+    );
+
+    let clearing = FixedClearingTimes {
+        full: Time::new::<millisecond>(197.0),
+        reduced: Time::new::<second>(2.5),
+    };
+    let worst = Scenario::new(&c, i_bf)
+        .evaluate_worst_case(&clearing)
+        .unwrap();
+    assert!(worst.full.e_afb.warnings().is_empty());
+    assert_eq!(worst.reduced.e_afb.warnings().len(), 1);
+}
@@ -0,0 +1,184 @@
+use std::fmt::{Display, Formatter};
+use uom::fmt::DisplayStyle::Abbreviation;
+use uom::si::electric_current::kiloampere;
+use uom::si::electric_potential::volt;
+use uom::si::f64::{ElectricCurrent, ElectricPotential, Length, Time};
+use uom::si::length::millimeter as mm;
+use uom::si::time::{millisecond, second};
+
+use crate::cubicle::Cubicle;
+use crate::e_afb::EAfb;
+use crate::i_arc::IArc;
+
+/// A condition that does not prevent a calculation, but where IEEE 1584-2018 advises caution or where an
+/// assumption has been applied.
+#[derive(PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "warning", rename_all = "snake_case"))]
+pub enum Warning {
+    /// The enclosure is less than 508 mm high and wide (and LV), but deeper than 203.2 mm, so it is treated as a
+    /// typical rather than a shallow enclosure.
+    ShallowEnclosureDepth {
+        #[cfg_attr(
+            feature = "serde",
+            serde(rename = "depth_mm", with = "crate::serde_units::millimeter")
+        )]
+        depth: Length,
+    },
+    /// The height or width of a typical enclosure is less than 508 mm, so 508 mm (20 in) is used for the
+    /// enclosure size correction factor.
+    EnclosureSizeIncreased {
+        #[cfg_attr(
+            feature = "serde",
+            serde(rename = "height_mm", with = "crate::serde_units::millimeter")
+        )]
+        height: Length,
+        #[cfg_attr(
+            feature = "serde",
+            serde(rename = "width_mm", with = "crate::serde_units::millimeter")
+        )]
+        width: Length,
+    },
+    /// The height or width of the enclosure exceeds 1244.6 mm (49 in), the largest size tested, so the equivalent
+    /// enclosure size is limited to that of a 1244.6 mm enclosure.
+    EnclosureSizeLimited {
+        #[cfg_attr(
+            feature = "serde",
+            serde(rename = "height_mm", with = "crate::serde_units::millimeter")
+        )]
+        height: Length,
+        #[cfg_attr(
+            feature = "serde",
+            serde(rename = "width_mm", with = "crate::serde_units::millimeter")
+        )]
+        width: Length,
+    },
+    /// The nominal voltage is 240 V or less. Sustained arcs are unlikely where the equipment is supplied by a
+    /// transformer rated less than 125 kVA, and the calculation may not be required.
+    LowVoltage240 {
+        #[cfg_attr(
+            feature = "serde",
+            serde(rename = "v_oc_kv", with = "crate::serde_units::kilovolt")
+        )]
+        v_oc: ElectricPotential,
+    },
+    /// The arc duration exceeds 2 seconds (IEEE 1584-2018 s6.9.1). Consider whether the worker could move away
+    /// from the arc within 2 seconds.
+    LongArcDuration {
+        #[cfg_attr(
+            feature = "serde",
+            serde(rename = "t_arc_ms", with = "crate::serde_units::millisecond")
+        )]
+        t_arc: Time,
+    },
+    /// The bolted fault current of a fault current profile falls below the range of the model (to `i_bf`) for a
+    /// total of `duration`. No incident energy is included for that time.
+    ProfileBelowRange {
+        #[cfg_attr(
+            feature = "serde",
+            serde(rename = "i_bf_ka", with = "crate::serde_units::kiloampere")
+        )]
+        i_bf: ElectricCurrent,
+        #[cfg_attr(
+            feature = "serde",
+            serde(rename = "duration_ms", with = "crate::serde_units::millisecond")
+        )]
+        duration: Time,
+    },
+    /// The result is by the Lee method, not IEEE 1584-2018. The arcing current is taken to be the bolted fault
+    /// current, which is conservative.
+    LeeMethod,
+    /// The result is for a DC arc, which is outside the scope of IEEE 1584-2018. It is by the DC arc models of
+    /// `dc_arc_flash`.
+    DcArc,
+}
+
+/// Maximum arc duration before a `Warning::LongArcDuration` is given.
+pub(crate) fn max_arc_duration() -> Time {
+    Time::new::<second>(2.0)
+}
+
+/// Warnings for an arcing current calculated for cubicle `c`, i.e. those of the cubicle.
+pub(crate) fn i_arc_warnings(c: &Cubicle) -> Vec<Warning> {
+    c.warnings.clone()
+}
+
+/// Warnings for an incident energy calculated for cubicle `c` with arc duration `t_arc`: those of the cubicle and,
+/// if the arc duration exceeds 2 seconds, `Warning::LongArcDuration`.
+pub(crate) fn e_afb_warnings(c: &Cubicle, t_arc: Time) -> Vec<Warning> {
+    let mut warnings = c.warnings.clone();
+    if t_arc > max_arc_duration() {
+        warnings.push(Warning::LongArcDuration { t_arc });
+    }
+    warnings
+}
+
+impl IArc {
+    /// Warnings that apply to the arcing current, i.e. those of the cubicle it was calculated for.
+    pub fn warnings(&self) -> &[Warning] {
+        match self {
+            IArc::HighVoltage(i_arc) => &i_arc.warnings,
+            IArc::LowVoltage(i_arc) => &i_arc.warnings,
+        }
+    }
+}
+
+impl EAfb {
+    /// Warnings that apply to the incident energy and AFB: those of the cubicle and, if the arc duration of this
+    /// result exceeds 2 seconds, `Warning::LongArcDuration`. For a multistep calculation these are the warnings of
+    /// the total (see `multistep_total`).
+    pub fn warnings(&self) -> &[Warning] {
+        match self {
+            EAfb::HighVoltage(e_afb) => &e_afb.warnings,
+            EAfb::LowVoltage(e_afb) => &e_afb.warnings,
+        }
+    }
+}
+
+impl Display for Warning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Warning::ShallowEnclosureDepth { depth } => write!(
+                f,
+                "enclosure depth ({}) exceeds 203.2 mm, so the enclosure is treated as typical rather than shallow",
+                depth.into_format_args(mm, Abbreviation)
+            ),
+            Warning::EnclosureSizeIncreased { height, width } => write!(
+                f,
+                "enclosure ({} high, {} wide) is smaller than 508 mm, so 508 mm is used for the enclosure size correction factor",
+                height.into_format_args(mm, Abbreviation),
+                width.into_format_args(mm, Abbreviation)
+            ),
+            Warning::EnclosureSizeLimited { height, width } => write!(
+                f,
+                "enclosure ({} high, {} wide) is larger than 1244.6 mm, so 1244.6 mm is used for the enclosure size correction factor",
+                height.into_format_args(mm, Abbreviation),
+                width.into_format_args(mm, Abbreviation)
+            ),
+            Warning::LowVoltage240 { v_oc } => write!(
+                f,
+                "V_oc = {} is 240 V or less: sustained arcs are unlikely if supplied by a transformer rated less than 125 kVA",
+                v_oc.into_format_args(volt, Abbreviation)
+            ),
+            Warning::LongArcDuration { t_arc } => write!(
+                f,
+                "arc duration ({}) exceeds 2 s",
+                t_arc.into_format_args(second, Abbreviation)
+            ),
+            Warning::ProfileBelowRange { i_bf, duration } => write!(
+                f,
+                "fault current falls below the range of the model (to {}) for {}: no incident energy is included for that time",
+                i_bf.into_format_args(kiloampere, Abbreviation),
+                duration.into_format_args(millisecond, Abbreviation)
+            ),
+            Warning::LeeMethod => write!(
+                f,
+                "Lee method, not IEEE 1584-2018: the arcing current is taken to be the bolted fault current"
+            ),
+            Warning::DcArc => write!(
+                f,
+                "DC arc, outside the scope of IEEE 1584-2018"
+            ),
+        }
+    }
+}
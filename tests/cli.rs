@@ -0,0 +1,191 @@
+//! Exit codes and argument parsing of the `arcflash` command line tool.
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+/// The options of `arcflash calc` for the Annex D.1 example.
+const D1: [&str; 14] = [
+    "--voltage",
+    "4.16kV",
+    "--ec",
+    "VCB",
+    "--gap",
+    "104mm",
+    "--distance",
+    "914.4mm",
+    "--box",
+    "1143x762x508mm",
+    "--ibf",
+    "15kA",
+    "--t",
+    "197ms",
+];
+
+fn arcflash(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_arcflash"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+/// `arcflash calc` with the options of the Annex D.1 example, with `option` replaced by (or added as) `value`.
+fn calc(option: &str, value: &str) -> Output {
+    let mut args = vec!["calc"];
+    let mut found = false;
+    for pair in D1.chunks(2) {
+        if pair[0] == option {
+            args.extend([option, value]);
+            found = true;
+        } else {
+            args.extend(pair);
+        }
+    }
+    if !found {
+        args.extend([option, value]);
+    }
+    arcflash(&args)
+}
+
+fn batch_stdin(input: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_arcflash"))
+        .args(["batch", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).to_string()
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr).to_string()
+}
+
+#[test]
+fn test_calc() {
+    let mut args = vec!["calc"];
+    args.extend(D1);
+    let output = arcflash(&args);
+    assert_eq!(output.status.code(), Some(0));
+    assert!(stdout(&output).contains("Cubicle parameters:"));
+
+    // `--key=value` is the same as `--key value`.
+    let mut args = vec!["calc".to_string()];
+    args.extend(D1.chunks(2).map(|pair| format!("{}={}", pair[0], pair[1])));
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let equals = arcflash(&args);
+    assert_eq!(equals.status.code(), Some(0));
+    assert_eq!(stdout(&equals), stdout(&output));
+
+    // Plain numbers are taken to be in the default units, as for batch input.
+    let plain = arcflash(&[
+        "calc",
+        "--voltage",
+        "4.16",
+        "--ec",
+        "VCB",
+        "--gap",
+        "104",
+        "--distance",
+        "914.4",
+        "--box",
+        "1143x762x508",
+        "--ibf",
+        "15",
+        "--t",
+        "197",
+    ]);
+    assert_eq!(plain.status.code(), Some(0));
+    assert_eq!(stdout(&plain), stdout(&output));
+
+    assert_eq!(calc("--t-reduced", "223ms").status.code(), Some(0));
+    assert_eq!(calc("--frequency", "50Hz").status.code(), Some(0));
+}
+
+#[test]
+fn test_help() {
+    for arg in ["help", "--help", "-h"] {
+        let output = arcflash(&[arg]);
+        assert_eq!(output.status.code(), Some(0));
+        assert!(stdout(&output).contains("Exit codes:"));
+    }
+}
+
+#[test]
+fn test_input_not_read() {
+    let output = arcflash(&["batch", "does-not-exist.csv"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stderr(&output).contains("does-not-exist.csv"));
+}
+
+#[test]
+fn test_invalid_command_line() {
+    // No command, an unknown command, or batch without a file.
+    assert_eq!(arcflash(&[]).status.code(), Some(2));
+    assert_eq!(arcflash(&["plot"]).status.code(), Some(2));
+    assert_eq!(arcflash(&["batch"]).status.code(), Some(2));
+
+    // An argument that is not an option, or an option without a value.
+    assert_eq!(arcflash(&["calc", "4.16kV"]).status.code(), Some(2));
+    let output = arcflash(&["calc", "--voltage"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(stderr(&output).contains("missing value for --voltage"));
+
+    // A missing option, or a value that cannot be read.
+    let output = arcflash(&["calc", "--voltage", "4.16kV"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(stderr(&output).contains("missing"));
+    assert_eq!(calc("--ec", "XYZ").status.code(), Some(2));
+    assert_eq!(calc("--gap", "104 furlongs").status.code(), Some(2));
+    assert_eq!(calc("--box", "1143x762").status.code(), Some(2));
+}
+
+#[test]
+fn test_cubicle_out_of_range() {
+    let output = calc("--gap", "10mm");
+    assert_eq!(output.status.code(), Some(3));
+    assert!(stderr(&output).contains("busbar gap G must be >= 19.05 mm"));
+
+    assert_eq!(calc("--voltage", "20kV").status.code(), Some(3));
+    assert_eq!(calc("--frequency", "400Hz").status.code(), Some(3));
+}
+
+#[test]
+fn test_ibf_out_of_range() {
+    let output = calc("--ibf", "100kA");
+    assert_eq!(output.status.code(), Some(4));
+    assert!(stderr(&output).contains("I_bf = 100 kA is outside calculation range"));
+}
+
+#[test]
+fn test_batch() {
+    let study = "\
+name,voltage,ec,gap,distance,box,ibf,t,t_reduced
+SWGR-1,4.16kV,VCB,104,914.4,1143x762x508,15,197,223
+";
+    let output = batch_stdin(study);
+    assert_eq!(output.status.code(), Some(0));
+    let out = stdout(&output);
+    assert!(out.starts_with("name,"));
+    assert!(out.lines().nth(1).unwrap().starts_with("SWGR-1,"));
+
+    // A row that fails does not stop the others.
+    let study = format!(
+        "{}SWGR-2,4.16kV,VCB,10,914.4,1143x762x508,15,197,223\n",
+        study
+    );
+    let output = batch_stdin(&study);
+    assert_eq!(output.status.code(), Some(5));
+    assert_eq!(stdout(&output).lines().count(), 3);
+    assert!(stderr(&output).contains("SWGR-2"));
+}